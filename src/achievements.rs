@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use crate::friend::{Friend, GrowthStage};
+use crate::shapes::creatures::SPECIES;
+
+const HOUR_MILLIS: i64 = 1000 * 60 * 60;
+/// How long the friend has to survive for the `SurvivedWeek` achievement.
+const WEEK_MILLIS: i64 = 7 * 24 * HOUR_MILLIS;
+/// How long the friend's food must stay above zero for the `WellFed` achievement.
+const WELL_FED_MILLIS: i64 = 48 * HOUR_MILLIS;
+
+/// All achievements, in the order they are displayed.
+pub const ACHIEVEMENTS: [Achievement; 5] = [
+    Achievement::ReachedAdult,
+    Achievement::SurvivedWeek,
+    Achievement::WellFed,
+    Achievement::RaisedEverySpecies,
+    Achievement::DiedAsEgg,
+];
+
+/// Milestones the player can reach while taking care of their pets.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Achievement {
+    ReachedAdult,
+    SurvivedWeek,
+    WellFed,
+    RaisedEverySpecies,
+    DiedAsEgg,
+}
+
+impl Achievement {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Achievement::ReachedAdult => "All grown up",
+            Achievement::SurvivedWeek => "One week strong",
+            Achievement::WellFed => "Well fed",
+            Achievement::RaisedEverySpecies => "Zookeeper",
+            Achievement::DiedAsEgg => "Cracked",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Achievement::ReachedAdult => "Raise a pet until it is an adult.",
+            Achievement::SurvivedWeek => "Keep a pet alive for 7 days.",
+            Achievement::WellFed => "Don't let your pet's food hit zero for 48 hours.",
+            Achievement::RaisedEverySpecies => "Raise every species until it is an adult.",
+            Achievement::DiedAsEgg => "Lose a pet before it has even hatched.",
+        }
+    }
+
+    /// Secret achievements don't show their description until they are unlocked.
+    pub fn is_secret(&self) -> bool {
+        *self == Achievement::DiedAsEgg
+    }
+}
+
+/// An achievement the player has unlocked, together with the moment it was unlocked.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnlockedAchievement {
+    pub achievement: Achievement,
+    pub unlocked_at: i64,
+}
+
+/// Keeps track of the achievements the player unlocked over all of their pets.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Achievements {
+    unlocked: Vec<UnlockedAchievement>,
+    raised_species: Vec<String>,
+}
+
+impl Achievements {
+    /// Checks the state of the friend for newly reached milestones.
+    /// <br>
+    /// ## parameters:
+    /// * `friend` - The friend the player is currently taking care of.
+    /// * `now` - The current utc time in millis.
+    ///
+    /// ## returns:
+    /// The achievements that were unlocked by this check.
+    pub fn check(&mut self, friend: &Friend, now: i64) -> Vec<Achievement> {
+        let is_adult = friend.growth_stage() == GrowthStage::Adult;
+        let alive = *friend.alive();
+
+        let species = friend.shape().species();
+        if is_adult && !self.raised_species.iter().any(|raised| raised == species) {
+            self.raised_species.push(String::from(species));
+        }
+
+        let mut reached = Vec::new();
+        if is_adult {
+            reached.push(Achievement::ReachedAdult);
+        }
+        if alive && now - friend.time_created() >= WEEK_MILLIS {
+            reached.push(Achievement::SurvivedWeek);
+        }
+        if alive && now - friend.well_fed_since() >= WELL_FED_MILLIS {
+            reached.push(Achievement::WellFed);
+        }
+        if SPECIES.iter().all(|species| self.raised_species.iter().any(|raised| raised == species)) {
+            reached.push(Achievement::RaisedEverySpecies);
+        }
+        if !alive && friend.growth_stage() == GrowthStage::Egg {
+            reached.push(Achievement::DiedAsEgg);
+        }
+
+        reached.retain(|achievement| !self.is_unlocked(*achievement));
+        for achievement in &reached {
+            self.unlocked.push(UnlockedAchievement { achievement: *achievement, unlocked_at: now });
+        }
+        reached
+    }
+
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked_at(achievement).is_some()
+    }
+
+    /// Returns the utc time in millis at which the given achievement was unlocked,
+    /// or `None` when it is still locked.
+    pub fn unlocked_at(&self, achievement: Achievement) -> Option<i64> {
+        self.unlocked.iter()
+            .find(|unlocked| unlocked.achievement == achievement)
+            .map(|unlocked| unlocked.unlocked_at)
+    }
+
+    pub fn unlocked_count(&self) -> usize {
+        self.unlocked.len()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::achievements::{Achievement, Achievements, HOUR_MILLIS};
+    use crate::environment::Environment;
+    use crate::friend::Friend;
+    use crate::shapes::creatures::CreatureShapes;
+    use crate::utils::ColorWrapper;
+    use crate::weather::Weather;
+
+    #[test]
+    fn achievements_unlock_only_once() {
+        let mut achievements = Achievements::default();
+        let mut friend = Friend::new("duck", CreatureShapes::Duck(ColorWrapper::Red));
+        let environment = Environment { lights_on: true, weather_seed: 0, has_bed: false };
+
+        let now = friend.time_created();
+        assert!(achievements.check(&friend, now).is_empty());
+
+        // Grown up, but the food ran out somewhere along the way.
+        let later = now + 30 * HOUR_MILLIS;
+        for hour in 1..=30 {
+            friend.update_state(now + hour * HOUR_MILLIS, &environment);
+        }
+        let unlocked = achievements.check(&friend, later);
+        assert_eq!(unlocked, vec![Achievement::ReachedAdult]);
+        assert_eq!(achievements.unlocked_at(Achievement::ReachedAdult), Some(later));
+
+        assert!(achievements.check(&friend, later).is_empty());
+        assert!(!achievements.is_unlocked(Achievement::WellFed));
+    }
+
+    #[test]
+    fn eggs_freeze_in_the_dark() {
+        let environment = |lights_on| Environment { lights_on, weather_seed: 0, has_bed: false };
+        // The start of the first snowy period in January.
+        let snowy = (0..)
+            .map(|period| 1_704_153_600_000 + period * 3 * HOUR_MILLIS)
+            .find(|timestamp| environment(false).weather_at(*timestamp) == Weather::Snowy)
+            .unwrap();
+
+        let mut warm_egg = Friend::born_at("duck", CreatureShapes::Duck(ColorWrapper::Red), snowy);
+        warm_egg.update_state(snowy + 60 * 1000, &environment(true));
+        assert!(*warm_egg.alive());
+
+        let mut achievements = Achievements::default();
+        let mut cold_egg = Friend::born_at("duck", CreatureShapes::Duck(ColorWrapper::Red), snowy);
+        cold_egg.update_state(snowy + 60 * 1000, &environment(false));
+        assert!(!*cold_egg.alive());
+        assert_eq!(achievements.check(&cold_egg, snowy + 60 * 1000), vec![Achievement::DiedAsEgg]);
+    }
+}
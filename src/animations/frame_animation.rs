@@ -0,0 +1,108 @@
+use chrono::Utc;
+use crate::animations::Animation;
+use crate::load_embedded_sprite;
+use crate::utils::{ColorWrapper, Pixel};
+use crate::utils::sprite_management::load_sprite;
+use crate::shapes::PixelVectorShape;
+
+/// How long every frame of a `FrameAnimation` is shown.
+const FRAME_MILLIS: i64 = 750;
+
+/// ## FrameAnimation
+/// Shows a sequence of sprites one after the other, every sprite for the same amount of time.
+/// This is used for the popups that appear after taking care of the pet, or after unlocking an achievement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameAnimation {
+    frames: Vec<Vec<Pixel>>,
+    current_frame: usize,
+    last_time_update: i64,
+}
+
+impl FrameAnimation {
+    /// Creates a new `FrameAnimation` that starts at the first frame.
+    /// <br>
+    /// ## parameters:
+    /// * `frames` - The sprites of the animation, in the order they are shown.
+    pub fn new(frames: Vec<Vec<Pixel>>) -> Self {
+        Self {
+            frames,
+            current_frame: 0,
+            last_time_update: Utc::now().timestamp_millis(),
+        }
+    }
+
+    /// A syringe, shown after the pet took its medicine.
+    pub fn health() -> Self {
+        Self::new(vec![
+            load_embedded_sprite!("../../assets/health/syringe.png", ColorWrapper::White),
+            load_embedded_sprite!("../../assets/health/syringe1.png", ColorWrapper::White),
+            load_embedded_sprite!("../../assets/health/syringe2.png", ColorWrapper::White),
+            load_embedded_sprite!("../../assets/health/syringe3.png", ColorWrapper::White),
+        ])
+    }
+
+    /// A basketball, shown after playing with the pet.
+    pub fn joy() -> Self {
+        Self::new(vec![
+            load_embedded_sprite!("../../assets/joy/basketball.png", ColorWrapper::White),
+            load_embedded_sprite!("../../assets/joy/basketball1.png", ColorWrapper::White),
+            load_embedded_sprite!("../../assets/joy/basketball2.png", ColorWrapper::White),
+            load_embedded_sprite!("../../assets/joy/basketball3.png", ColorWrapper::White),
+        ])
+    }
+
+    /// A heart, shown after petting the pet.
+    pub fn pet() -> Self {
+        Self::new(vec![
+            load_embedded_sprite!("../../assets/pet/heart.png", ColorWrapper::Red),
+            load_embedded_sprite!("../../assets/pet/heart1.png", ColorWrapper::Red),
+            load_embedded_sprite!("../../assets/pet/heart2.png", ColorWrapper::Red),
+            load_embedded_sprite!("../../assets/pet/heart3.png", ColorWrapper::Red),
+        ])
+    }
+
+    /// A trophy, shown after unlocking an achievement.
+    pub fn achievement() -> Self {
+        Self::new(vec![
+            load_embedded_sprite!("../../assets/achievement/trophy.png", ColorWrapper::Yellow),
+            load_embedded_sprite!("../../assets/achievement/trophy1.png", ColorWrapper::Yellow),
+            load_embedded_sprite!("../../assets/achievement/trophy2.png", ColorWrapper::Yellow),
+            load_embedded_sprite!("../../assets/achievement/trophy3.png", ColorWrapper::Yellow),
+        ])
+    }
+}
+
+impl Animation for FrameAnimation {
+    fn next_frame(&mut self) -> Option<PixelVectorShape> {
+        let pixel_vec = self.frames.get(self.current_frame)?.clone();
+
+        let now = Utc::now().timestamp_millis();
+        if now - self.last_time_update >= FRAME_MILLIS {
+            self.current_frame += 1;
+            self.last_time_update = now;
+        }
+
+        Some(PixelVectorShape::new(pixel_vec))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+    use crate::animations::{Animation, FrameAnimation};
+    use crate::utils::Pixel;
+
+    #[test]
+    fn frames_are_shown_in_order() {
+        let frame = |width| (0..width).map(|x| Pixel { x, y: 0, color: Color::White }).collect::<Vec<Pixel>>();
+        let mut animation = FrameAnimation::new(vec![frame(1), frame(2)]);
+
+        assert_eq!(animation.next_frame().unwrap().get_dimensions().0, 1);
+        animation.last_time_update -= 1000;
+        assert_eq!(animation.next_frame().unwrap().get_dimensions().0, 1);
+        animation.last_time_update -= 1000;
+        assert_eq!(animation.next_frame().unwrap().get_dimensions().0, 2);
+        assert!(animation.next_frame().is_none());
+    }
+}
@@ -1,13 +1,11 @@
 mod popup_animation;
 mod animation;
+mod frame_animation;
 pub mod food_animation;
-pub mod health_animation;
-pub mod joy_animation;
 mod sleeping_animation;
 
 pub use animation::Animation;
 pub use popup_animation::PopupAnimation;
 
-pub use health_animation::HealthAnimation;
-pub use joy_animation::JoyAnimation;
+pub use frame_animation::FrameAnimation;
 pub use sleeping_animation::SleepingAnimation;
\ No newline at end of file
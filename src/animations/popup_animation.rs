@@ -1,9 +1,12 @@
 use ratatui::{widgets::{Block, Clear}, Frame};
 use ratatui::layout::Rect;
-use ratatui::widgets::canvas::Canvas;
+use ratatui::text::Line;
+use crate::renderer::Renderer;
 use crate::shapes::PixelVectorShape;
 use super::animation::Animation;
 
+/// The size of the canvas the animations are drawn on, when the popup is large enough.
+const CANVAS_SIZE: (u16, u16) = (50, 30);
 
 /// ## PopupAnimation
 /// PopupAnimations can be used to display a short little animation 
@@ -13,7 +16,7 @@ pub struct PopupAnimation {
     is_running: bool,
     animation: Box<dyn Animation>,
     animation_dimensions: (u16, u16),
-    area: Rect,
+    title: Option<String>,
 }
 
 impl PopupAnimation {
@@ -27,20 +30,43 @@ impl PopupAnimation {
             is_running: true,
             animation,
             animation_dimensions,
-            area: Rect::new(0, 0, 50, 30),
+            title: None,
         }
     }
 
+    /// Shows the given title on the border of the popup, for example to explain what the animation is about.
+    /// <br>
+    /// ## parameters:
+    /// * `title` - The text to show on top of the popup.
+    pub fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
     /// Renders the PopupAnimation on the `ratatui::Frame`. 
     /// This method automatically updates the animations state.
     /// ## parameters: 
     /// * `frame` - The `ratatui::Frame` to render the PopupAnimation on
     pub fn render(&mut self, frame: &mut Frame) {
+        let popup_area = self.get_popup_rect(frame);
+        let mut block = Block::bordered();
+        if let Some(title) = &self.title {
+            block = block.title(Line::from(title.as_str()).centered());
+        }
+
+        // Large popups scale the animation up, but small popups never scale it down,
+        // so no part of the animation gets lost.
+        let renderer = Renderer::global();
+        let (columns, rows) = renderer.pixels_per_cell();
+        let inner_area = block.inner(popup_area);
+        let width = CANVAS_SIZE.0.min((f64::from(inner_area.width) * columns) as u16);
+        let height = CANVAS_SIZE.1.min((f64::from(inner_area.height) * rows) as u16);
+
         let next_animation_frame =
             if let Some(animation_frame) = self.animation.next_frame() {
                 animation_frame.translate(
-                    self.area.width as i32 / 2, 
-                    self.area.height as i32 / 2,
+                    width as i32 / 2, 
+                    height as i32 / 2,
                 ).translate( // Account for the animation sprite dimensions
                              -(self.animation_dimensions.0 as i32 / 2),
                              -(self.animation_dimensions.1 as i32 / 2),
@@ -50,15 +76,12 @@ impl PopupAnimation {
                 PixelVectorShape::new(Vec::new())
             };
 
-        let canvas = Canvas::default()
-            .block(Block::bordered())
-            .x_bounds([0.0, f64::from(self.area.width)])
-            .y_bounds([0.0, f64::from(self.area.height)])
-            .paint(|ctx| {
+        let canvas = renderer
+            .canvas([0.0, f64::from(width)], [0.0, f64::from(height)], |ctx| {
                 ctx.draw(&next_animation_frame)
-            });
+            })
+            .block(block);
         
-        let popup_area = self.get_popup_rect(frame);
         frame.render_widget(Clear, popup_area); // Clear out the background behind the popup.
         frame.render_widget(canvas, popup_area);
     }
@@ -71,6 +94,7 @@ impl PopupAnimation {
 
     /// Returns a rectangle that has 1/3 the width and height of
     /// the given frame, which is also centered in the frame.
+    /// On small terminals the popup is made larger, so the whole animation still fits.
     ///
     /// ## parameters:
     /// * `frame` - The `ratatui::Frame` to use for calculating the popup area.
@@ -79,8 +103,12 @@ impl PopupAnimation {
     /// A `ratatui::layout::Rect` instance that corresponds to the popup area.
     fn get_popup_rect(&self, frame: &Frame) -> Rect {
         let area = frame.area();
-        let popup_width = area.width / 3;
-        let popup_height = area.height / 3;
+        // The size of the animation in cells, plus the borders.
+        let (columns, rows) = Renderer::global().pixels_per_cell();
+        let min_width = (f64::from(self.animation_dimensions.0) / columns).ceil() as u16 + 2;
+        let min_height = (f64::from(self.animation_dimensions.1) / rows).ceil() as u16 + 2;
+        let popup_width = (area.width / 3).max(min_width).min(area.width);
+        let popup_height = (area.height / 3).max(min_height).min(area.height);
         let popup_x = (area.width - popup_width) / 2;
         let popup_y = (area.height - popup_height) / 2;
 
@@ -1,25 +1,24 @@
-use chrono::Utc;
 use crate::animations::Animation;
-use crate::load_embedded_sprite;
 use crate::utils::sprite_management::load_sprite;
 use crate::shapes::PixelVectorShape;
-use crate::utils::ColorWrapper;
+use crate::theme::Theme;
 use crate::utils::location::Location;
 
 pub struct SleepingAnimation {
     friend_location: Location,
+    now: i64,
 }
 
 impl Animation for SleepingAnimation {
     fn next_frame(&mut self) -> Option<PixelVectorShape> {
-        let shape = load_embedded_sprite!("../../assets/sleeping/zz.png", ColorWrapper::White);
+        let sprite = include_bytes!("../../assets/sleeping/zz.png");
+        let shape = load_sprite(sprite, Theme::global().sleep_color()).expect("Failed to load sprite");
         let shape = PixelVectorShape::new(shape).translate(
             self.friend_location.x as i32,
             self.friend_location.y as i32,
         );
         
-        let now = Utc::now().timestamp_millis();
-        if (now / 1000) % 2 == 0 {
+        if (self.now / 1000) % 2 == 0 {
             Some(shape.translate(20, 20))
         } else {
             Some(shape.translate(20, 22))
@@ -28,9 +27,15 @@ impl Animation for SleepingAnimation {
 }
 
 impl SleepingAnimation {
-    pub fn new(friend_location: Location) -> Self {
+    /// Creates a new `SleepingAnimation` for a friend sleeping at the given location.
+    /// <br>
+    /// ## parameters:
+    /// * `friend_location` - The location of the sleeping friend.
+    /// * `now` - The current utc time in millis, which decides where the "zz" floats.
+    pub fn new(friend_location: Location, now: i64) -> Self {
         Self {
             friend_location,
+            now,
         }
     }
 }
\ No newline at end of file
@@ -1,20 +1,88 @@
-use std::time::Duration;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 use ratatui::{Frame, DefaultTerminal};
-use ratatui::widgets::ListState;
+use ratatui::widgets::{Block, ListState};
 use ratatui::layout::{Constraint, Layout};
-use ratatui::layout::Rect;
-use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, poll};
+use ratatui::layout::{Position, Rect};
+use ratatui::crossterm::event::{self, Event, KeyEventKind, MouseButton, MouseEvent, MouseEventKind, poll};
+use ratatui::widgets::Clear;
+use crate::renderer::Renderer;
+use crate::species::SpeciesMovement;
 use crate::game_state::GameState;
+use crate::daemon::{Request, Response, Session};
 use crate::movements::{Movement, MovementWrapper, EggHopMovement, SmallStepsMovement, DvdBounceMovement};
-use crate::friend::{Friend, GrowthStage};
-use crate::widgets::{FriendWidget, actions_widget, StatsWidgetGenerator};
+use crate::friend::GrowthStage;
+use crate::widgets::{FriendWidget, actions_widget, actions_hint, StatsWidgetGenerator, TutorialWidget};
+use crate::widgets::too_small_widget::too_small_message;
+use crate::widgets::message_widget::message_box;
+use crate::widgets::help_widget::{centered_area, HelpPage, HelpWidget};
+use crate::widgets::playground_area_at;
+use crate::keymap::{KeyAction, Keymap};
 use crate::utils::location::Location;
 use crate::layouts;
 use crate::food::Food;
+use crate::item::Item;
 use crate::shapes::PixelVectorShape;
 use crate::animations::PopupAnimation;
-use crate::animations::food_animation::{FoodAnimation, FoodAnimationFrames};
-use crate::animations::{HealthAnimation, JoyAnimation};
+use crate::animations::food_animation::FoodAnimationFrames;
+use crate::animations::FrameAnimation;
+use crate::achievements::Achievement;
+use crate::config::Config;
+use crate::game_loop::GameLoop;
+use crate::weather::Weather;
+
+const SECOND_MILLIS: i64 = 1000;
+const MINUTE_MILLIS: i64 = 60 * SECOND_MILLIS;
+/// How long a message, like the reason an action failed, is shown.
+const MESSAGE_DURATION: Duration = Duration::from_secs(4);
+/// The smallest terminal the game can be played in.
+const MIN_WIDTH: u16 = 24;
+const MIN_HEIGHT: u16 = 10;
+/// Terminals smaller than this use the compact screen.
+const COMPACT_WIDTH: u16 = 40;
+const COMPACT_HEIGHT: u16 = 20;
+/// Below this width the stats, the playground and the actions are stacked on top of each other.
+const NARROW_WIDTH: u16 = 80;
+/// The stacked screen needs more rows, narrow terminals with fewer rows use the compact screen.
+const STACKED_HEIGHT: u16 = 28;
+
+/// How the main screen is laid out, which depends on the size of the terminal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ScreenMode {
+    /// The terminal is too small to play in, so the player is asked to make it larger.
+    TooSmall,
+    /// For tiny panes, like a tmux split. The creature is drawn at half its size, the stats are shown
+    /// as single line gauges and the actions list is replaced by the hotkeys of the most used actions.
+    Compact,
+    /// The stats, the playground and the actions stacked on top of each other.
+    Stacked,
+    /// The stats, the playground and the actions next to each other.
+    Wide,
+}
+
+impl ScreenMode {
+    /// Returns the screen mode that fits the terminal best.
+    /// <br>
+    /// ## parameters:
+    /// * `area` - The area of the whole terminal.
+    /// * `force_compact` - When true, the compact screen is used for every terminal that is large enough to play in.
+    fn for_area(area: Rect, force_compact: bool) -> Self {
+        let is_narrow = area.width < NARROW_WIDTH;
+        if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+            ScreenMode::TooSmall
+        } else if force_compact
+            || area.width < COMPACT_WIDTH
+            || area.height < COMPACT_HEIGHT
+            || (is_narrow && area.height < STACKED_HEIGHT)
+        {
+            ScreenMode::Compact
+        } else if is_narrow {
+            ScreenMode::Stacked
+        } else {
+            ScreenMode::Wide
+        }
+    }
+}
 
 /// This struct holds most logic for actually running the app. It is able to run the Termagotchi app
 /// using a `ratatui::DefaultTerminal` and keeps track of: game state, widget states, movements and animations.
@@ -23,7 +91,7 @@ use crate::animations::{HealthAnimation, JoyAnimation};
 /// ```
 /// fn main() -> std::io::Result<()> {
 ///     let mut terminal = ratatui::init();
-///     let mut app = App::new(&mut terminal)?;
+///     let mut app = App::new(&mut terminal, &Config::default())?;
 ///     
 ///     app.run(&mut terminal)?;
 ///     app.save_game()?;
@@ -33,45 +101,75 @@ use crate::animations::{HealthAnimation, JoyAnimation};
 /// }
 /// ```
 pub struct App {
-    game_state: GameState,
+    session: Session,
     actions_widget_state: ListState,
     previous_growth_stage: GrowthStage,
     friend_movement: MovementWrapper,
     playground: Rect,
+    screen_mode: ScreenMode,
+    force_compact: bool,
+    /// Where the friend and the actions were drawn last, used to find out what the player clicked on.
+    friend_area: Rect,
+    friend_location: Location,
+    actions_area: Rect,
     popup_animation: Option<PopupAnimation>,
+    achievement_toasts: VecDeque<Achievement>,
+    /// A message for the player together with the moment it was shown, like the reason an action failed.
+    message: Option<(String, Instant)>,
     allow_inputs: bool,
+    /// The inputs that came in while inputs weren't allowed, which are handled once the popup animation has finished.
+    blocked_events: VecDeque<Event>,
+    /// The page of the help overlay, or `None` when the help is closed.
+    help_page: Option<HelpPage>,
+    game_loop: GameLoop,
     is_running: bool,
 }
 
 impl App {
-    pub fn new(terminal: &mut DefaultTerminal) -> std::io::Result<Self> {
+    pub fn new(terminal: &mut DefaultTerminal, config: &Config) -> std::io::Result<Self> {
         let actions_widget_state = ListState::default();
-        let playground = Self::get_playground(terminal);
+        let frame_area = terminal.get_frame().area();
+        let screen_mode = ScreenMode::for_area(frame_area, config.compact);
+        let playground = get_playground(frame_area, screen_mode);
 
-        let game_state: GameState;
-        if let Ok(state) = GameState::read_from_file() {
-            game_state = state;
+        // Connect to the daemon when it is running, otherwise play using the save file.
+        let session: Session;
+        if let Ok(opened) = Session::open() {
+            session = opened;
 
         } else {
-            game_state = layouts::draw_new_game_layout(terminal)?;
+            session = Session::local(layouts::draw_new_game_layout(terminal)?)?;
         }
 
+        let game_state = session.game_state();
         let previous_growth_stage = game_state.friend().growth_stage();
 
         let friend_movement = get_movement_wrapper(
             &game_state.friend().growth_stage(),
+            game_state.friend().shape().movement(),
             playground,
             game_state.friend().get_pixel_vector(),
+            game_state.item_bounds(playground),
         );
         
         Ok(Self {
-            game_state,
+            session,
             actions_widget_state,
             previous_growth_stage,
             friend_movement,
             playground,
+            screen_mode,
+            force_compact: config.compact,
+            friend_area: Rect::default(),
+            friend_location: Location::new(0, 0),
+            actions_area: Rect::default(),
             popup_animation: None,
+            achievement_toasts: VecDeque::new(),
+            message: None,
             allow_inputs: true,
+            blocked_events: VecDeque::new(),
+            help_page: None,
+            game_loop: GameLoop::new(config.fps, Instant::now()),
             is_running: true,
         })
     }
@@ -82,137 +180,383 @@ impl App {
     /// * `terminal` - The ratatui terminal to draw the application on.
     pub fn run(&mut self, terminal: &mut DefaultTerminal) -> std::io::Result<()> {
         while self.is_running {
-            self.game_state.update();
-            if !self.game_state.friend().alive() {
-                layouts::friend_death_layout(terminal, &mut self.game_state)?;
+            let now = Instant::now();
+            let ticks = self.game_loop.take_ticks(now);
+            if ticks > 0 && self.update(terminal, ticks)? {
+                self.game_loop.mark_dirty();
             }
 
-            if self.previous_growth_stage != self.game_state.friend().growth_stage() {
-                self.previous_growth_stage = self.game_state.friend().growth_stage();
-
-                update_friend_movement(&mut self.friend_movement, self.game_state.friend(), self.playground);
+            if self.game_loop.should_draw(now, self.is_animating()) {
+                self.draw(terminal)?;
             }
-            
-            terminal.draw(|frame| {
-                self.draw_main(frame);
-
-                // Check if a popup animation should be displayed, or hidden when it has finished.
-                if let Some(popup_animation) = &mut self.popup_animation {
-                    self.allow_inputs = false;
-                    if popup_animation.is_running() {
-                        popup_animation.render(frame);
-                    } else {
-                        self.popup_animation = None;
-                        self.allow_inputs = true;
-                    }
-                }
-            })?;
 
-            self.handle_inputs()?;
+            self.handle_inputs(terminal)?;
         }
         
         Ok(())
     }
-    
-    /// Gets the area where the creature will reside in based on the terminal size.
+
+    /// Updates the game logic, this is called once every logic tick of the game loop.
+    /// <br>
     /// ## parameters:
-    /// * `terminal` - The terminal that will be used to render the application.
-    fn get_playground(terminal: &mut DefaultTerminal) -> Rect {
-        let frame = terminal.get_frame();
-        let frame_area = frame.area();
-        let [_, mut playground, _] = get_main_areas(frame_area);
-        playground.x = 0;
-        playground.y = 0;
-        playground.width *= 2;
-        playground.height *= 4;
-        playground
+    /// * `terminal` - The ratatui terminal, used to show the death screen when the friend died.
+    /// * `ticks` - The number of logic ticks that passed since the last update.
+    ///
+    /// ## returns:
+    /// If the screen changed, because the friend moved, the game state changed, or the clock went to the next minute.
+    /// While the friend is asleep its snoring moves every second.
+    fn update(&mut self, terminal: &mut DefaultTerminal, ticks: u32) -> std::io::Result<bool> {
+        let clock_step = if self.session.game_state().friend().is_asleep() { SECOND_MILLIS } else { MINUTE_MILLIS };
+        let revision = self.session.game_state().revision();
+        let step = self.session.game_state().now() / clock_step;
+        let position = self.friend_movement.position();
+
+        let mut changed = self.session.refresh()?;
+        self.achievement_toasts.extend(self.session.take_new_achievements());
+        if let Some(notice) = self.session.take_notice() {
+            self.show_message(notice);
+        }
+        if !self.session.game_state().friend().alive() {
+            layouts::friend_death_layout(terminal, &mut self.session)?;
+            changed = true;
+        }
+
+        if self.message.as_ref().is_some_and(|(_, shown_at)| shown_at.elapsed() >= MESSAGE_DURATION) {
+            self.message = None;
+            self.game_loop.mark_dirty();
+        }
+
+        // Announce unlocked achievements one at a time, once other animations have finished.
+        if self.popup_animation.is_none() {
+            if let Some(achievement) = self.achievement_toasts.pop_front() {
+                self.set_achievement_animation(achievement);
+            }
+        }
+
+        // The save file might have been changed by another process, in which case the friend
+        // could have grown, or be a different friend altogether.
+        if changed || self.previous_growth_stage != self.session.game_state().friend().growth_stage() {
+            self.previous_growth_stage = self.session.game_state().friend().growth_stage();
+
+            update_friend_movement(&mut self.friend_movement, self.session.game_state(), self.playground);
+        }
+
+        // The friend stands still while nobody is watching, to save power.
+        if !self.session.game_state().friend().is_asleep() && !self.game_loop.is_low_power() {
+            for _ in 0..ticks {
+                self.friend_movement.tick();
+            }
+        }
+
+        // The age, the weather and the sky follow the clock, even when the state itself didn't change.
+        Ok(changed
+            || revision != self.session.game_state().revision()
+            || step != self.session.game_state().now() / clock_step
+            || position != self.friend_movement.position())
+    }
+
+    /// Returns true while something on the screen moves on its own, like a popup animation or falling rain.
+    fn is_animating(&self) -> bool {
+        let game_state = self.session.game_state();
+        let now = game_state.now();
+        self.popup_animation.is_some()
+            || (game_state.lights_on() && game_state.weather(now) != Weather::Sunny)
+    }
+
+    /// Draws the current screen, with the help and popup animations on top of it.
+    fn draw(&mut self, terminal: &mut DefaultTerminal) -> std::io::Result<()> {
+        terminal.draw(|frame| {
+            // The terminal might also have been resized while another screen, like the shop, was open.
+            self.resize(frame.area());
+            if self.screen_mode == ScreenMode::TooSmall {
+                // Animations can't be shown either, skip them so the player can still use their keys.
+                self.popup_animation = None;
+                self.allow_inputs = true;
+                let message_area = centered_area(frame.area(), frame.area().width, 3);
+                frame.render_widget(too_small_message(frame.area(), MIN_WIDTH, MIN_HEIGHT), message_area);
+                return;
+            }
+
+            self.draw_main(frame);
+
+            // Check if a popup animation should be displayed, or hidden when it has finished.
+            if let Some(popup_animation) = &mut self.popup_animation {
+                self.allow_inputs = false;
+                if popup_animation.is_running() {
+                    popup_animation.render(frame);
+                } else {
+                    self.popup_animation = None;
+                    self.allow_inputs = true;
+                    self.game_loop.mark_dirty();
+                }
+            }
+
+            if let Some(page) = self.help_page {
+                let help = HelpWidget::new(page, Keymap::global());
+                let help_area = centered_area(frame.area(), 70, help.height());
+                frame.render_widget(Clear, help_area);
+                frame.render_widget(help, help_area);
+            }
+        })?;
+
+        Ok(())
     }
     
-    /// Saves the game's state to a file by calling `GameState::store_to_file()`.
+    /// Rebuilds the playground when the size of the terminal changed, so the creature keeps walking
+    /// around in the part of the playground that is visible.
+    /// <br>
+    /// ## parameters:
+    /// * `frame_area` - The area of the whole terminal.
+    fn resize(&mut self, frame_area: Rect) {
+        self.screen_mode = ScreenMode::for_area(frame_area, self.force_compact);
+        let playground = get_playground(frame_area, self.screen_mode);
+        if playground != self.playground {
+            self.playground = playground;
+            update_friend_movement(&mut self.friend_movement, self.session.game_state(), self.playground);
+        }
+    }
+
+    /// Shows a message to the player at the bottom of the playground for a couple of seconds.
+    /// <br>
+    /// ## parameters:
+    /// * `message` - The message to show, like the reason an action failed.
+    pub fn show_message(&mut self, message: String) {
+        self.message = Some((message, Instant::now()));
+        self.game_loop.mark_dirty();
+    }
+
+    /// Saves the game's state to a file by calling `Session::save()`, this is left to the daemon when it is running.
     pub fn save_game(&mut self) -> std::io::Result<()> {
-        self.game_state.store_to_file()
+        self.session.save()
     }
 
     /// Draws the main screen of the application, which allows for users to interact with their friend.
     fn draw_main(&mut self, frame: &mut Frame) {
         let frame_area = frame.area();
-        let [left_area, middle_area, right_area] = get_main_areas(frame_area);
+        let [left_area, middle_area, right_area] = get_main_areas(frame_area, self.screen_mode);
         
-        let bar_stats = StatsWidgetGenerator::new(self.game_state.friend());
+        let bar_stats = StatsWidgetGenerator::new(self.session.game_state().friend());
         
-        let friend_widget = if !self.game_state.friend().is_asleep() {
-            FriendWidget::new(self.game_state.friend(), self.friend_movement.next_position(), self.playground)
+        let friend_location = if !self.session.game_state().friend().is_asleep() {
+            self.friend_movement.position()
         } else {
-            FriendWidget::new(self.game_state.friend(), self.sleep_drawing_location(), self.playground)
+            self.sleep_drawing_location()
         };
+        let friend_widget = FriendWidget::new(self.session.game_state(), friend_location, self.playground);
+        self.friend_area = middle_area;
+        self.friend_location = friend_location;
         
-        
-        frame.render_widget(bar_stats.get_widget(), left_area);
         frame.render_widget(friend_widget.get_widget(), middle_area);
-        frame.render_stateful_widget(actions_widget(), right_area, &mut self.actions_widget_state);
+        if self.screen_mode == ScreenMode::Compact {
+            // There is no room for the actions list, so it can't be clicked either.
+            self.actions_area = Rect::default();
+            render_stat_gauges(frame, left_area, &bar_stats);
+            frame.render_widget(actions_hint(Keymap::global(), self.session.is_read_only()), right_area);
+            self.draw_overlays(frame, middle_area);
+            return;
+        }
+
+        self.actions_area = right_area;
+        frame.render_widget(bar_stats.get_widget(), left_area);
+        frame.render_stateful_widget(actions_widget(self.session.game_state().inventory(), self.session.is_read_only()), right_area, &mut self.actions_widget_state);
+        self.draw_overlays(frame, middle_area);
+    }
+
+    /// Draws the tutorial and the message for the player at the bottom of the playground, when there are any.
+    /// A message is more urgent, so it is drawn on top of the tutorial.
+    /// <br>
+    /// ## parameters:
+    /// * `frame` - The frame to draw on.
+    /// * `friend_area` - The area the friend widget is drawn in.
+    fn draw_overlays(&self, frame: &mut Frame, friend_area: Rect) {
+        if self.session.game_state().tutorial().is_active() {
+            frame.render_widget(TutorialWidget::new(self.session.game_state(), Keymap::global()), TutorialWidget::area(friend_area));
+        }
+
+        if let Some((message, _)) = &self.message {
+            let message_area = TutorialWidget::area(friend_area);
+            frame.render_widget(Clear, message_area);
+            frame.render_widget(message_box(message), message_area);
+        }
     }
 
-    fn handle_inputs(&mut self) -> std::io::Result<()> {
-        if self.allow_inputs && poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-
-                    match key.code {
-                        KeyCode::Char('q') => self.is_running = false,
-
-                        KeyCode::Up => self.actions_widget_state.select_previous(),
-                        KeyCode::Down => self.actions_widget_state.select_next(),
-                        KeyCode::Enter => {
-                            if let Some(action) = self.actions_widget_state.selected() {
-                                let action = actions_widget::ITEMS[action];
-                                let is_awake = !self.game_state.friend().is_asleep();
-                                let is_not_egg = self.game_state.friend().growth_stage() != GrowthStage::Egg;
-                                match action {
-                                    "Eat" => {
-                                        if is_awake && is_not_egg {
-                                            let food = Food::new_random();
-                                            self.set_food_animation(food);
-                                            self.game_state.friend_mut().eat(food);
-                                            self.game_state.store_to_file()?;
-                                        }
-                                    },
-                                    "Play" => {
-                                        if is_awake && is_not_egg {
-                                            self.set_joy_animation();
-                                            self.game_state.friend_mut().play();
-                                            self.game_state.store_to_file()?;
-                                        }
-                                    },
-                                    "Sleep" => {
-                                        if is_not_egg {
-                                            self.game_state.friend_mut().toggle_sleep();
-                                            self.game_state.store_to_file()?;
-                                        }
-                                    },
-                                    "Medicine" => {
-                                        if is_awake && is_not_egg {
-                                            self.set_health_animation();
-                                            self.game_state.friend_mut().take_medicine();
-                                            self.game_state.store_to_file()?;
-                                        }
-                                    },
-                                    _ => ()
-                                }
-                            }
-                        },
-                        _ => ()
+    fn handle_inputs(&mut self, terminal: &mut DefaultTerminal) -> std::io::Result<()> {
+        let blocked_event = if self.allow_inputs { self.blocked_events.pop_front() } else { None };
+        let event = match blocked_event {
+            Some(event) => event,
+            None if poll(self.game_loop.timeout(Instant::now(), self.is_animating()))? => event::read()?,
+            None => return Ok(()),
+        };
+
+        self.game_loop.mark_dirty();
+        match event {
+            Event::FocusLost => self.game_loop.set_low_power(true, Instant::now()),
+            Event::FocusGained => self.game_loop.set_low_power(false, Instant::now()),
+            _ if !self.allow_inputs => {
+                // Inputs wait until the popup animation has finished, but the focus is followed right away.
+                self.blocked_events.push_back(event);
+                return Ok(());
+            },
+            _ => (),
+        }
+        if let Event::Resize(width, height) = event {
+            self.resize(Rect::new(0, 0, width, height));
+            return Ok(());
+        }
+        if let Event::Mouse(mouse) = event {
+            // The help covers the main screen, so clicks are ignored while it is shown.
+            if self.help_page.is_none() && self.screen_mode != ScreenMode::TooSmall {
+                self.handle_mouse(terminal, mouse)?;
+            }
+            return Ok(());
+        }
+        if let Event::Key(key) = event {
+            if key.kind == KeyEventKind::Press {
+                let keymap = Keymap::global();
+
+                // While the help is shown, the only thing the player can do is flip through its pages or close it again.
+                if let Some(page) = self.help_page {
+                    if keymap.is(key.code, KeyAction::Help) || keymap.is(key.code, KeyAction::Back) {
+                        self.help_page = None;
+                    } else if keymap.is(key.code, KeyAction::Switch) {
+                        self.help_page = Some(page.next());
                     }
+                    return Ok(());
+                }
+
+                match keymap.action(key.code) {
+                    Some(KeyAction::Quit) => self.is_running = false,
+                    Some(KeyAction::Help) => self.help_page = Some(HelpPage::default()),
+                    Some(KeyAction::Back) if self.session.game_state().tutorial().is_active() => {
+                        let response = self.session.perform(Request::FinishTutorial)?;
+                        self.set_response_animation(response);
+                    },
+                    Some(KeyAction::Achievements) => layouts::achievements_layout(terminal, self.session.game_state())?,
+                    Some(KeyAction::Statistics) => layouts::statistics_layout(terminal, self.session.game_state())?,
+
+                    // The compact screen has no actions list to move through.
+                    Some(KeyAction::Up | KeyAction::Down) if self.screen_mode == ScreenMode::Compact => (),
+                    Some(KeyAction::Up) => actions_widget::select_previous(&mut self.actions_widget_state),
+                    Some(KeyAction::Down) => actions_widget::select_next(&mut self.actions_widget_state),
+                    Some(KeyAction::Select) => {
+                        if let Some(action) = actions_widget::selected_action(&self.actions_widget_state) {
+                            self.perform_action(terminal, action)?;
+                        }
+                    },
+                    Some(key_action) => {
+                        // Hotkeys select their action in the list as well, so the player can see what happened.
+                        if let Some(action) = key_action.action_item() {
+                            self.actions_widget_state.select(actions_widget::ITEMS.iter().position(|item| *item == action));
+                            self.perform_action(terminal, action)?;
+                        }
+                    },
+                    None => (),
                 }
             }
         }
         Ok(())
     }
 
-    fn set_food_animation(&mut self, food: Food) {
-        if self.game_state.friend().food().is_max() {
-            return;
+    /// Handles the mouse. Clicking an action performs it, just like selecting it with the keyboard,
+    /// and clicking the friend pets it.
+    /// <br>
+    /// ## parameters:
+    /// * `terminal` - The terminal, used to draw the shop when it is opened.
+    /// * `mouse` - The mouse event to handle.
+    fn handle_mouse(&mut self, terminal: &mut DefaultTerminal, mouse: MouseEvent) -> std::io::Result<()> {
+        match mouse.kind {
+            // Frames aren't drawn after every event, so the selection is kept within the list here instead of by the list widget.
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown if self.screen_mode == ScreenMode::Compact => (),
+            MouseEventKind::ScrollUp => actions_widget::select_previous(&mut self.actions_widget_state),
+            MouseEventKind::ScrollDown => actions_widget::select_next(&mut self.actions_widget_state),
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(action) = self.clicked_action(mouse.column, mouse.row) {
+                    self.actions_widget_state.select(Some(action));
+                    if let Some(action) = actions_widget::selected_action(&self.actions_widget_state) {
+                        self.perform_action(terminal, action)?;
+                    }
+                } else if self.clicked_friend(mouse.column, mouse.row) {
+                    let response = self.session.perform(Request::Pet)?;
+                    self.set_response_animation(response);
+                }
+            },
+            _ => (),
         }
-        
+        Ok(())
+    }
+
+    /// Returns the index of the action that is drawn at the given cell, if there is one.
+    fn clicked_action(&self, column: u16, row: u16) -> Option<usize> {
+        let inner = Block::bordered().inner(self.actions_area);
+        if !inner.contains(Position::new(column, row)) {
+            return None;
+        }
+
+        let action = usize::from(row - inner.y) + self.actions_widget_state.offset();
+        (action < actions_widget::ITEMS.len()).then_some(action)
+    }
+
+    /// Returns true when the friend is drawn at the given cell.
+    fn clicked_friend(&self, column: u16, row: u16) -> bool {
+        let Some(clicked) = playground_area_at(self.friend_area, self.playground, column, row) else {
+            return false;
+        };
+
+        let (width, height) = self.session.game_state().friend().get_pixel_vector().get_dimensions();
+        let friend_bounds = Rect::new(
+            self.friend_location.x as u16,
+            self.friend_location.y as u16,
+            width as u16,
+            height as u16,
+        );
+        clicked.intersects(friend_bounds)
+    }
+
+    /// Performs one of the actions from the actions list.
+    /// <br>
+    /// ## parameters:
+    /// * `terminal` - The terminal, used to draw the shop when it is opened.
+    /// * `action` - The name of the action, as it is shown in the actions list.
+    fn perform_action(&mut self, terminal: &mut DefaultTerminal, action: &str) -> std::io::Result<()> {
+        let request = match action {
+            "Eat" => Some(Request::Feed),
+            "Play" => Some(Request::Play),
+            "Sleep" => Some(Request::Sleep),
+            "Medicine" => Some(Request::Medicine),
+            "Lights" => Some(Request::Lights),
+            "Scenery" => Some(Request::Scenery),
+            _ => None,
+        };
+        if let Some(request) = request {
+            let response = self.session.perform(request)?;
+            self.set_response_animation(response);
+        }
+
+        if action == "Shop" {
+            layouts::shop_layout(terminal, &mut self.session)?;
+            // New toys or furniture might have been bought, which the friend should walk around.
+            update_friend_movement(&mut self.friend_movement, self.session.game_state(), self.playground);
+        }
+        Ok(())
+    }
+
+    /// Plays the animation that belongs to the outcome of an action, if there is one,
+    /// or tells the player why the action couldn't be performed.
+    /// <br>
+    /// ## parameters:
+    /// * `response` - The outcome of the action the player performed.
+    fn set_response_animation(&mut self, response: Response) {
+        match response {
+            Response::Error { message } => self.show_message(message),
+            Response::Fed { food: Some(food) } => self.set_food_animation(food),
+            Response::Played { enjoyed: true } => self.set_joy_animation(),
+            Response::Petted { enjoyed: true } => self.set_pet_animation(),
+            Response::TookMedicine { took_medicine: true } => self.set_health_animation(),
+            _ => (),
+        }
+    }
+
+    fn set_food_animation(&mut self, food: Food) {
         let frames = match food {
             Food::Soup => FoodAnimationFrames::Soup,
             Food::Cookie => FoodAnimationFrames::Cookie,
@@ -220,65 +564,95 @@ impl App {
         };
 
         self.popup_animation = Some(PopupAnimation::new(
-            Box::new(FoodAnimation::new(frames)),
+            Box::new(FrameAnimation::new(frames.frames().to_vec())),
             (15, 15),
         ))
     }
     
     fn set_health_animation(&mut self) {
-        if self.game_state.friend().health().is_max() {
-            return;
-        }
-        
         self.popup_animation = Some(PopupAnimation::new(
-            Box::new(HealthAnimation::new()),
+            Box::new(FrameAnimation::health()),
             (15, 15)
         ))
     }
     
     fn set_joy_animation(&mut self) {
-        if self.game_state.friend().joy().is_max() {
-            return;
-        }
-        
         self.popup_animation = Some(PopupAnimation::new(
-            Box::new(JoyAnimation::new()),
+            Box::new(FrameAnimation::joy()),
             (15, 15)
         ))
     }
     
-    fn sleep_drawing_location(&self) -> Location {
-        let mut center = Location {
-            x: self.playground.width as u32 / 2,
-            y: self.playground.height as u32 / 2,
-        };
+    fn set_pet_animation(&mut self) {
+        self.popup_animation = Some(PopupAnimation::new(
+            Box::new(FrameAnimation::pet()),
+            (15, 15)
+        ))
+    }
+    
+    fn set_achievement_animation(&mut self, achievement: Achievement) {
+        self.popup_animation = Some(PopupAnimation::new(
+            Box::new(FrameAnimation::achievement()),
+            (15, 15)
+        ).with_title(format!(" Achievement unlocked: {} ", achievement.name())))
+    }
 
-        let sprite_width = match self.game_state.friend().growth_stage() {
-            GrowthStage::Egg => 10,
-            GrowthStage::Baby => 10,
-            GrowthStage::Kid => 15,
-            GrowthStage::Adult => 25,
-        };
+    /// Returns the location the friend is drawn at while it's asleep. When there is a bed in the
+    /// playground the friend sleeps on top of it, otherwise it sleeps in the center of the playground.
+    fn sleep_drawing_location(&self) -> Location {
+        let bed = self.session.game_state().items().iter().find(|placed| placed.item == Item::Bed);
+        if let Some(bed) = bed {
+            let bed_bounds = bed.bounds(self.playground);
+            let (friend_width, _) = self.session.game_state().friend().get_pixel_vector().get_dimensions();
+            let x = u32::from(bed_bounds.x) + (u32::from(bed_bounds.width).saturating_sub(friend_width)) / 2;
+            let y = u32::from(bed_bounds.y) + u32::from(bed_bounds.height) / 2;
+            return Location::new(x, y);
+        }
 
-        center.x -= sprite_width / 2;
-        center.y -= sprite_width / 2;
-        center
+        let (sprite_width, sprite_height) = self.session.game_state().friend().get_pixel_vector().get_dimensions();
+        Location {
+            x: (self.playground.width as u32 / 2).saturating_sub(sprite_width / 2),
+            y: (self.playground.height as u32 / 2).saturating_sub(sprite_height / 2),
+        }
     }
 }
 
 
 
-/// Returns the 3 areas used on the main screen in an array.
+/// Returns the 3 areas used on the main screen in an array, for the stats, the playground and the actions.
+/// How the areas are placed depends on the screen mode.
 ///
 /// ## Parameters
 /// * `area` - The area of the `ratatui::Frame` the widgets will be drawn to.
+/// * `screen_mode` - The screen mode that fits the terminal.
 ///
 /// ## Example usage:
 /// ```
 /// let frame_area = frame.area();
-/// let [left_area, middle_area, right_area] = get_main_areas(frame_area);
+/// let [left_area, middle_area, right_area] = get_main_areas(frame_area, ScreenMode::Wide);
 /// ```
-fn get_main_areas(area: Rect) -> [Rect; 3] {
+fn get_main_areas(area: Rect, screen_mode: ScreenMode) -> [Rect; 3] {
+    if screen_mode == ScreenMode::Compact {
+        // The hotkeys need a second line on the smallest terminals.
+        let hint_height = if area.width < COMPACT_WIDTH { 2 } else { 1 };
+        return Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(hint_height),
+        ])
+            .areas(area);
+    }
+
+    if screen_mode == ScreenMode::Stacked {
+        return Layout::vertical([
+            // One row per stat, and one for each of the borders.
+            Constraint::Length(6),
+            Constraint::Fill(1),
+            Constraint::Length(actions_widget::ITEMS.len() as u16 + 2),
+        ])
+            .areas(area);
+    }
+
     Layout::horizontal([
         Constraint::Percentage(15),
         Constraint::Percentage(70),
@@ -287,23 +661,78 @@ fn get_main_areas(area: Rect) -> [Rect; 3] {
         .areas(area)
 }
 
+/// Gets the area where the creature will reside in based on the terminal size.
+/// ## parameters:
+/// * `frame_area` - The area of the terminal the application is rendered on.
+/// * `screen_mode` - The screen mode that fits the terminal. The compact screen uses a playground
+///   twice as large, which makes everything in it appear at half its size.
+///
+/// The playground is measured in pixels, which depends on how many pixels the renderer draws in a cell.
+fn get_playground(frame_area: Rect, screen_mode: ScreenMode) -> Rect {
+    let [_, mut playground, _] = get_main_areas(frame_area, screen_mode);
+    let scale = if screen_mode == ScreenMode::Compact { 2.0 } else { 1.0 };
+    let (columns, rows) = Renderer::global().pixels_per_cell();
+    playground.x = 0;
+    playground.y = 0;
+    playground.width = (f64::from(playground.width) * columns * scale) as u16;
+    playground.height = (f64::from(playground.height) * rows * scale) as u16;
+    playground
+}
+
+/// Renders the stats as single line gauges, two next to each other on both lines of the area.
+fn render_stat_gauges(frame: &mut Frame, area: Rect, stats: &StatsWidgetGenerator) {
+    let rows: [Rect; 2] = Layout::vertical([Constraint::Length(1); 2]).areas(area);
+    let gauge_areas = rows.iter().flat_map(|row| {
+        let columns: [Rect; 2] = Layout::horizontal([Constraint::Fill(1); 2]).spacing(2).areas(*row);
+        columns
+    });
+
+    for (gauge, gauge_area) in stats.get_gauges().into_iter().zip(gauge_areas) {
+        frame.render_widget(gauge, gauge_area);
+    }
+}
+
 
 /// Updates the movement of the creature based on its growth stage.
 /// <br>
 /// ## parameters:
 /// * `movement` - The movement that should be modified.
-/// * `friend` - The friend that will be used to check the growth stage.
+/// * `game_state` - The game state that will be used to check the friend's growth stage and the placed items.
 /// * `area` - The area where the creature walks around in, used to set movement boundaries.
-fn update_friend_movement(movement: &mut MovementWrapper, friend: &Friend, area: Rect) {
+fn update_friend_movement(movement: &mut MovementWrapper, game_state: &GameState, area: Rect) {
+    let friend = game_state.friend();
     let shape = friend.get_pixel_vector();
-    *movement = get_movement_wrapper(&friend.growth_stage(), area, shape);
+    *movement = get_movement_wrapper(&friend.growth_stage(), friend.shape().movement(), area, shape, game_state.item_bounds(area));
 }
 
-fn get_movement_wrapper(growth_stage: &GrowthStage, area: Rect, friend_shape: PixelVectorShape) -> MovementWrapper {
+fn get_movement_wrapper(
+    growth_stage: &GrowthStage,
+    adult_movement: SpeciesMovement,
+    area: Rect,
+    friend_shape: PixelVectorShape,
+    obstacles: Vec<Rect>,
+) -> MovementWrapper {
     let center = Location::new(area.width as u32 / 2, area.height as u32 / 2);
-    match growth_stage {
-        GrowthStage::Egg => MovementWrapper::EggHop(EggHopMovement::new(center)),
-        GrowthStage::Baby => MovementWrapper::SmallSteps(SmallStepsMovement::new(center)),
-        _ => MovementWrapper::DvdBounce(DvdBounceMovement::new(center, area, friend_shape)),
+    match (growth_stage, adult_movement) {
+        (GrowthStage::Egg, _) | (GrowthStage::Adult, SpeciesMovement::Hop) => MovementWrapper::EggHop(EggHopMovement::new(center, &friend_shape)),
+        (GrowthStage::Baby, _) | (GrowthStage::Adult, SpeciesMovement::SmallSteps) => MovementWrapper::SmallSteps(SmallStepsMovement::new(center, &friend_shape)),
+        _ => MovementWrapper::DvdBounce(DvdBounceMovement::new(center, area, friend_shape, obstacles)),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+    use crate::app::ScreenMode;
+
+    #[test]
+    fn screen_mode_fits_the_terminal() {
+        assert_eq!(ScreenMode::for_area(Rect::new(0, 0, 200, 50), false), ScreenMode::Wide);
+        assert_eq!(ScreenMode::for_area(Rect::new(0, 0, 200, 50), true), ScreenMode::Compact);
+        assert_eq!(ScreenMode::for_area(Rect::new(0, 0, 60, 40), false), ScreenMode::Stacked);
+        assert_eq!(ScreenMode::for_area(Rect::new(0, 0, 60, 20), false), ScreenMode::Compact);
+        assert_eq!(ScreenMode::for_area(Rect::new(0, 0, 30, 12), false), ScreenMode::Compact);
+        assert_eq!(ScreenMode::for_area(Rect::new(0, 0, 20, 8), true), ScreenMode::TooSmall);
     }
 }
@@ -0,0 +1,68 @@
+use std::time::Instant;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+use crate::friend::Friend;
+use crate::game_state::GameState;
+use crate::renderer::Renderer;
+use crate::shapes::creatures::CreatureShapes;
+use crate::utils::ColorWrapper;
+use crate::utils::location::Location;
+use crate::utils::sprite_management::{get_colored_pixels, load_sprite};
+use crate::widgets::FriendWidget;
+
+/// How many frames are drawn, enough to even out the first frame that decodes the sprites.
+const FRAMES: u32 = 1000;
+/// How many times every sprite is loaded, both with and without the cache.
+const LOADS: u32 = 100;
+/// The sprites drawn most often, which are loaded to compare decoding them with the cache.
+const SPRITES: [&[u8]; 4] = [
+    include_bytes!("../assets/egg.png"),
+    include_bytes!("../assets/kid.png"),
+    include_bytes!("../assets/duck.png"),
+    include_bytes!("../assets/sleeping/zz.png"),
+];
+
+/// Draws the playground of a new pet over and over, like the game does every frame, and prints how long
+/// drawing a single frame takes. Nothing is saved, so it doesn't touch the save file.
+/// It also compares how long loading the sprites takes when they are decoded every time and when they come from the cache.
+/// Run it with `cargo run --release -- benchmark`, debug builds are a lot slower.
+pub fn run_benchmark() -> std::io::Result<()> {
+    let game_state = GameState::new(Friend::new("Benchmark", CreatureShapes::Duck(ColorWrapper::Red)));
+    let area = Rect::new(0, 0, 120, 40);
+    let (columns, rows) = Renderer::global().pixels_per_cell();
+    let playground = Rect::new(0, 0, (f64::from(area.width) * columns) as u16, (f64::from(area.height) * rows) as u16);
+    let location = Location::new(u32::from(playground.width) / 2, u32::from(playground.height) / 2);
+    let mut buffer = Buffer::empty(area);
+
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        buffer.reset();
+        FriendWidget::new(&game_state, location, playground).get_widget().render(area, &mut buffer);
+    }
+    let elapsed = start.elapsed();
+
+    println!("Drew {FRAMES} frames of {}x{} cells in {elapsed:?}, {:?} per frame", area.width, area.height, elapsed / FRAMES);
+
+    let color = ColorWrapper::Red.get_ratatui_color();
+    let start = Instant::now();
+    for _ in 0..LOADS {
+        for sprite in SPRITES {
+            get_colored_pixels(sprite)?;
+        }
+    }
+    let decoded = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..LOADS {
+        for sprite in SPRITES {
+            load_sprite(sprite, color)?;
+        }
+    }
+    let cached = start.elapsed();
+
+    let loads = LOADS * SPRITES.len() as u32;
+    println!("Decoded {loads} sprites in {decoded:?}, {:?} per sprite", decoded / loads);
+    println!("Loaded {loads} cached sprites in {cached:?}, {:?} per sprite", cached / loads);
+    Ok(())
+}
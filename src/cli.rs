@@ -0,0 +1,110 @@
+use crate::daemon::Request;
+
+/// The ways Termagotchi can be started, chosen with the first command line argument.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Opens the game in the terminal, this is the default when no command is given.
+    Game,
+    /// Runs in the background, and notifies the player when their pet needs attention.
+    Notify,
+    /// Runs in the background, and keeps the game state up to date for all other instances of the game.
+    Daemon,
+    /// Takes care of the pet from the command line, without opening the game.
+    Action(Request),
+    /// Measures how long drawing a frame takes, without opening the game.
+    Benchmark,
+}
+
+pub const USAGE: &str = "usage: termagotchi [--time-scale <factor>] [notify | daemon | status | feed | play | sleep | medicine | benchmark]";
+
+impl Command {
+    /// Parses the command from the command line arguments, without the name of the program.
+    /// <br>
+    /// ## parameters:
+    /// * `args` - The command line arguments.
+    ///
+    /// ## returns:
+    /// [std::io::ErrorKind::InvalidInput] when the arguments aren't a known command.
+    pub fn parse(mut args: impl Iterator<Item = String>) -> std::io::Result<Self> {
+        let command = match args.next().as_deref() {
+            None => Command::Game,
+            Some("notify") => Command::Notify,
+            Some("daemon") => Command::Daemon,
+            Some("status") => Command::Action(Request::Status),
+            Some("feed") => Command::Action(Request::Feed),
+            Some("play") => Command::Action(Request::Play),
+            Some("sleep") => Command::Action(Request::Sleep),
+            Some("medicine") => Command::Action(Request::Medicine),
+            Some("benchmark") => Command::Benchmark,
+            Some(unknown) => return Err(invalid_input(format!("unknown command '{unknown}'\n{USAGE}"))),
+        };
+
+        if let Some(extra) = args.next() {
+            return Err(invalid_input(format!("unexpected argument '{extra}'\n{USAGE}")));
+        }
+        Ok(command)
+    }
+}
+
+/// Takes the `--time-scale <factor>` debug flag out of the command line arguments. The game then runs
+/// the given number of times faster than the real time, which makes it possible to watch the pet grow up.
+/// <br>
+/// ## parameters:
+/// * `args` - The command line arguments, the flag and its value are removed from them.
+///
+/// ## returns:
+/// [std::io::ErrorKind::InvalidInput] when the factor is missing, or isn't a positive number.
+pub fn take_time_scale(args: &mut Vec<String>) -> std::io::Result<Option<f64>> {
+    let Some(index) = args.iter().position(|arg| arg == "--time-scale") else {
+        return Ok(None);
+    };
+
+    args.remove(index);
+    if index >= args.len() {
+        return Err(invalid_input(format!("missing factor for --time-scale\n{USAGE}")));
+    }
+
+    let value = args.remove(index);
+    match value.parse::<f64>() {
+        Ok(scale) if scale.is_finite() && scale > 0.0 => Ok(Some(scale)),
+        _ => Err(invalid_input(format!("invalid factor for --time-scale '{value}'\n{USAGE}"))),
+    }
+}
+
+fn invalid_input(message: String) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidInput, message)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{take_time_scale, Command};
+    use crate::daemon::Request;
+
+    fn parse(args: &[&str]) -> std::io::Result<Command> {
+        Command::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(parse(&[]).unwrap(), Command::Game);
+        assert_eq!(parse(&["notify"]).unwrap(), Command::Notify);
+        assert_eq!(parse(&["feed"]).unwrap(), Command::Action(Request::Feed));
+        assert_eq!(parse(&["benchmark"]).unwrap(), Command::Benchmark);
+        assert!(parse(&["pet"]).is_err());
+        assert!(parse(&["notify", "now"]).is_err());
+    }
+
+    #[test]
+    fn takes_time_scale() {
+        let mut args: Vec<String> = ["--time-scale", "60", "feed"].iter().map(|arg| arg.to_string()).collect();
+        assert_eq!(take_time_scale(&mut args).unwrap(), Some(60.0));
+        assert_eq!(args, vec!["feed"]);
+        assert_eq!(take_time_scale(&mut args).unwrap(), None);
+
+        for invalid in [&["--time-scale"][..], &["--time-scale", "0"], &["--time-scale", "fast"]] {
+            let mut args: Vec<String> = invalid.iter().map(|arg| arg.to_string()).collect();
+            assert!(take_time_scale(&mut args).is_err());
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::Debug;
+#[cfg(test)]
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, OnceLock};
+use chrono::Utc;
+
+static CLOCK: OnceLock<Arc<dyn Clock>> = OnceLock::new();
+
+/// Tells the game what time it is. Everything that depends on the time asks a clock,
+/// so tests can use a fake clock, and the game can run faster than the real time.
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current utc time in millis.
+    fn now_millis(&self) -> i64;
+
+    /// Returns true when the clock doesn't run at the real time.
+    fn is_scaled(&self) -> bool {
+        false
+    }
+}
+
+/// Sets the clock used by the whole game. Only the first call has an effect.
+pub fn set_global(clock: Arc<dyn Clock>) {
+    let _ = CLOCK.set(clock);
+}
+
+/// Returns the clock used by the whole game, which is the system clock when none was set.
+pub fn global() -> Arc<dyn Clock> {
+    Arc::clone(CLOCK.get_or_init(|| Arc::new(SystemClock)))
+}
+
+/// The real time, as told by the system.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_millis(&self) -> i64 {
+        Utc::now().timestamp_millis()
+    }
+}
+
+/// A clock that only moves when it is told to, so tests can decide exactly what time it is.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub struct FakeClock {
+    millis: AtomicI64,
+}
+
+#[cfg(test)]
+impl FakeClock {
+    /// Creates a new `FakeClock` that stands still at the given time.
+    /// <br>
+    /// ## parameters:
+    /// * `millis` - The utc time in millis the clock starts at.
+    pub fn new(millis: i64) -> Self {
+        Self { millis: AtomicI64::new(millis) }
+    }
+
+    /// Moves the clock forward by the given amount of millis.
+    pub fn advance(&self, millis: i64) {
+        self.millis.fetch_add(millis, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+impl Clock for FakeClock {
+    fn now_millis(&self) -> i64 {
+        self.millis.load(Ordering::SeqCst)
+    }
+}
+
+/// A clock that starts at the real time, but then runs a number of times faster (or slower) than the real time.
+#[derive(Debug, Copy, Clone)]
+pub struct ScaledClock {
+    started_at: i64,
+    scale: f64,
+}
+
+impl ScaledClock {
+    /// Creates a new `ScaledClock` that starts at the current time.
+    /// <br>
+    /// ## parameters:
+    /// * `scale` - How much faster the clock runs, 60 turns every real minute into an hour.
+    pub fn new(scale: f64) -> Self {
+        Self {
+            started_at: SystemClock.now_millis(),
+            scale,
+        }
+    }
+}
+
+impl Clock for ScaledClock {
+    fn now_millis(&self) -> i64 {
+        let elapsed = SystemClock.now_millis() - self.started_at;
+        self.started_at + (elapsed as f64 * self.scale) as i64
+    }
+
+    fn is_scaled(&self) -> bool {
+        true
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::clock::{Clock, FakeClock, ScaledClock, SystemClock};
+
+    #[test]
+    fn clocks_tell_the_time() {
+        let clock = FakeClock::new(1000);
+        clock.advance(500);
+        assert_eq!(clock.now_millis(), 1500);
+
+        let scaled = ScaledClock::new(1000.0);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert!(scaled.now_millis() - SystemClock.now_millis() >= 10_000);
+        assert!(scaled.is_scaled());
+        assert!(!SystemClock.is_scaled());
+    }
+}
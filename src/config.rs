@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::keymap::KeyAction;
+use crate::renderer::Renderer;
+use crate::theme::Theme;
+use std::fs::OpenOptions;
+use std::io::Read;
+
+const CONFIG_FILE_PATH: &str = "./config.json";
+
+/// Settings the player can change by creating a `config.json` file next to the save file.
+/// Settings that are missing from the file keep their default value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// A shell command that is run instead of showing a desktop notification.
+    /// The title and message are passed in the `TERMAGOTCHI_TITLE` and `TERMAGOTCHI_MESSAGE` environment variables.
+    pub notify_command: Option<String>,
+    /// The value at which a stat is low enough to notify the player about it.
+    pub critical_threshold: u32,
+    /// The keys bound to actions, replacing the default keys of those actions.
+    pub keys: HashMap<KeyAction, Vec<String>>,
+    /// Always use the compact screen, which is otherwise only used when the terminal is small.
+    pub compact: bool,
+    /// The colors the game is drawn with. Setting the `NO_COLOR` environment variable always uses the monochrome theme.
+    pub theme: Theme,
+    /// The characters the sprites are drawn with.
+    pub renderer: Renderer,
+    /// The most frames that are drawn per second. Frames are only drawn when something changed on the screen.
+    pub fps: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            notify_command: None,
+            critical_threshold: 20,
+            keys: HashMap::new(),
+            compact: false,
+            theme: Theme::default(),
+            renderer: Renderer::default(),
+            fps: 10,
+        }
+    }
+}
+
+impl Config {
+    pub fn read_from_file() -> std::io::Result<Self> {
+        let mut config_file = OpenOptions::new().read(true).open(CONFIG_FILE_PATH)?;
+
+        let mut config_buffer = String::new();
+        config_file.read_to_string(&mut config_buffer)?;
+
+        let config: Self = serde_json::from_str(&config_buffer)?;
+        Ok(config)
+    }
+
+    /// Reads the config file, falling back to the default settings when there is no config file.
+    pub fn load() -> std::io::Result<Self> {
+        match Self::read_from_file() {
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use super::{Request, Response, SOCKET_PATH};
+
+/// A connection to a running daemon.
+pub struct DaemonClient {
+    reader: BufReader<UnixStream>,
+    writer: UnixStream,
+}
+
+impl DaemonClient {
+    /// Connects to the daemon, this fails when no daemon is running.
+    pub fn connect() -> std::io::Result<Self> {
+        Self::from_stream(UnixStream::connect(SOCKET_PATH)?)
+    }
+
+    pub fn from_stream(stream: UnixStream) -> std::io::Result<Self> {
+        Ok(Self {
+            writer: stream.try_clone()?,
+            reader: BufReader::new(stream),
+        })
+    }
+
+    /// Sends a request to the daemon and waits for its response.
+    /// <br>
+    /// ## parameters:
+    /// * `request` - The request to send.
+    ///
+    /// ## returns:
+    /// The response of the daemon, which is `Response::Error` when the daemon couldn't handle the request.
+    /// An error is only returned when the connection to the daemon failed.
+    pub fn send(&mut self, request: &Request) -> std::io::Result<Response> {
+        let mut line = serde_json::to_string(request)?;
+        line.push('\n');
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.flush()?;
+
+        let mut response_line = String::new();
+        if self.reader.read_line(&mut response_line)? == 0 {
+            return Err(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "the daemon closed the connection"));
+        }
+
+        Ok(serde_json::from_str(&response_line)?)
+    }
+}
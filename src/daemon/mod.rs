@@ -0,0 +1,20 @@
+mod protocol;
+mod session;
+#[cfg(unix)]
+mod client;
+#[cfg(unix)]
+mod server;
+#[cfg(not(unix))]
+mod unsupported;
+
+pub use protocol::{Request, Response};
+pub use session::Session;
+#[cfg(unix)]
+pub use client::DaemonClient;
+#[cfg(unix)]
+pub use server::run_daemon;
+#[cfg(not(unix))]
+pub use unsupported::{DaemonClient, run_daemon};
+
+/// The socket the daemon listens on, next to the save file.
+const SOCKET_PATH: &str = "./termagotchi.sock";
@@ -0,0 +1,239 @@
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::achievements::Achievement;
+use crate::food::Food;
+use crate::friend::{Friend, GrowthStage};
+use crate::game_state::GameState;
+use crate::shapes::creatures::CreatureShapes;
+use crate::shop::ShopItem;
+
+/// A request to look at or take care of the pet. Requests are sent to the daemon as a single line of JSON,
+/// or applied to the local game state directly when no daemon is running.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Request {
+    /// A short summary of the pet's state, for the command line or a shell prompt.
+    Status,
+    /// The revision of the game state, which is a cheap way to check if the game state changed.
+    Revision,
+    /// The complete game state, used by the TUI to draw the pet.
+    State,
+    /// The achievements that were unlocked since they were last asked for. Only the TUI asks for them,
+    /// as asking for them means they are announced to the player.
+    Achievements,
+    Feed,
+    Play,
+    /// Pets the pet, which is done by clicking it in the game.
+    Pet,
+    Sleep,
+    Medicine,
+    Lights,
+    Scenery,
+    Buy { item: ShopItem },
+    /// Closes the tutorial for new players.
+    FinishTutorial,
+    /// Adopts a new pet with the given name, after the previous one has died.
+    NewFriend { name: String },
+}
+
+/// The answer to a `Request`, also sent as a single line of JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Response {
+    Status(StatusReport),
+    Revision { revision: u64 },
+    State {
+        game_state: Box<GameState>,
+        revision: u64,
+    },
+    Achievements { new_achievements: Vec<Achievement> },
+    Fed { food: Option<Food> },
+    Played { enjoyed: bool },
+    Petted { enjoyed: bool },
+    TookMedicine { took_medicine: bool },
+    Bought { bought: bool },
+    Done,
+    Error { message: String },
+}
+
+impl Request {
+    /// Returns true for requests that change the game state, after which the game should be saved.
+    pub fn changes_state(&self) -> bool {
+        !matches!(self, Request::Status | Request::Revision | Request::State | Request::Achievements)
+    }
+
+    /// Performs the request on the given game state. This is shared by the daemon and the
+    /// game itself, so both take care of the pet in exactly the same way.
+    /// <br>
+    /// ## parameters:
+    /// * `game_state` - The game state to apply the request to.
+    pub fn apply(self, game_state: &mut GameState) -> Response {
+        if self.changes_state() {
+            game_state.mark_changed();
+        }
+
+        match self {
+            Request::Status => Response::Status(StatusReport::new(game_state)),
+            Request::Revision => Response::Revision { revision: game_state.revision() },
+            Request::State => Response::State {
+                game_state: Box::new(game_state.clone()),
+                revision: game_state.revision(),
+            },
+            Request::Achievements => Response::Achievements { new_achievements: game_state.take_new_achievements() },
+            Request::Feed => Response::Fed { food: game_state.feed() },
+            Request::Play => Response::Played { enjoyed: game_state.play() },
+            Request::Pet => Response::Petted { enjoyed: game_state.pet() },
+            Request::Sleep => {
+                game_state.toggle_sleep();
+                Response::Done
+            },
+            Request::Medicine => Response::TookMedicine { took_medicine: game_state.give_medicine() },
+            Request::Lights => {
+                game_state.toggle_lights();
+                Response::Done
+            },
+            Request::Scenery => {
+                game_state.cycle_scenery();
+                Response::Done
+            },
+            Request::Buy { item } => Response::Bought { bought: game_state.buy(item) },
+            Request::FinishTutorial => {
+                game_state.finish_tutorial();
+                Response::Done
+            },
+            // Only a dead friend can be replaced, so a second game that also showed the death screen
+            // doesn't replace the friend the first one just named.
+            Request::NewFriend { .. } if *game_state.friend().alive() => Response::Error {
+                message: String::from("your pet is still alive"),
+            },
+            Request::NewFriend { name } => {
+                let friend = Friend::born_at(&name, CreatureShapes::new_random(), game_state.now());
+                game_state.replace_friend(friend);
+                Response::Done
+            },
+        }
+    }
+}
+
+/// A short summary of the pet's state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusReport {
+    pub name: String,
+    pub growth_stage: GrowthStage,
+    pub alive: bool,
+    pub asleep: bool,
+    pub food: u32,
+    pub joy: u32,
+    pub energy: u32,
+    pub health: u32,
+    pub coins: u32,
+}
+
+impl StatusReport {
+    pub fn new(game_state: &GameState) -> Self {
+        let friend = game_state.friend();
+        Self {
+            name: String::from(friend.name()),
+            growth_stage: friend.growth_stage(),
+            alive: *friend.alive(),
+            asleep: friend.is_asleep(),
+            food: friend.food().value(),
+            joy: friend.joy().value(),
+            energy: friend.energy().value(),
+            health: friend.health().value(),
+            coins: game_state.inventory().coins(),
+        }
+    }
+}
+
+impl Display for StatusReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.alive {
+            return write!(f, "{} has died", self.name);
+        }
+
+        write!(
+            f,
+            "{} ({:?}{}) food: {} joy: {} energy: {} health: {} coins: {}",
+            self.name,
+            self.growth_stage,
+            if self.asleep { ", asleep" } else { "" },
+            self.food, self.joy, self.energy, self.health, self.coins,
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::achievements::Achievement;
+    use crate::clock;
+    use crate::daemon::{Request, Response};
+    use crate::environment::Environment;
+    use crate::friend::Friend;
+    use crate::game_state::GameState;
+    use crate::shapes::creatures::CreatureShapes;
+    use crate::utils::ColorWrapper;
+
+    #[test]
+    fn requests_are_single_json_lines() {
+        let request: Request = serde_json::from_str(r#"{"type":"feed"}"#).unwrap();
+        assert_eq!(request, Request::Feed);
+
+        let serialized = serde_json::to_string(&Request::NewFriend { name: String::from("duck") }).unwrap();
+        assert_eq!(serialized, r#"{"type":"new_friend","name":"duck"}"#);
+    }
+
+    #[test]
+    fn eggs_cannot_be_fed() {
+        let mut game_state = GameState::new(Friend::new("egg", CreatureShapes::Duck(ColorWrapper::Red)));
+
+        assert!(matches!(Request::Feed.apply(&mut game_state), Response::Fed { food: None }));
+        match Request::Status.apply(&mut game_state) {
+            Response::Status(status) => assert_eq!(status.food, 50),
+            response => panic!("unexpected response: {response:?}"),
+        }
+    }
+
+    #[test]
+    fn only_the_achievements_request_takes_new_achievements() {
+        let born = clock::global().now_millis() - 2 * 24 * 60 * 60 * 1000;
+        let mut game_state = GameState::new(Friend::born_at("duck", CreatureShapes::Duck(ColorWrapper::Red), born));
+        let revision = game_state.revision();
+        // The friend grows a single stage every update.
+        for _ in 0..3 {
+            game_state.update();
+        }
+        assert_ne!(game_state.revision(), revision);
+
+        // Looking at the game state doesn't take the achievements away from the game itself.
+        let revision = game_state.revision();
+        assert!(matches!(Request::State.apply(&mut game_state), Response::State { revision: state_revision, .. } if state_revision == revision));
+        match Request::Achievements.apply(&mut game_state) {
+            Response::Achievements { new_achievements } => assert!(new_achievements.contains(&Achievement::ReachedAdult)),
+            response => panic!("unexpected response: {response:?}"),
+        }
+        assert!(matches!(Request::Achievements.apply(&mut game_state), Response::Achievements { new_achievements } if new_achievements.is_empty()));
+
+        assert!(matches!(Request::Revision.apply(&mut game_state), Response::Revision { revision: unchanged } if unchanged == revision));
+        Request::Lights.apply(&mut game_state);
+        assert_ne!(game_state.revision(), revision);
+    }
+
+    #[test]
+    fn only_dead_friends_are_replaced() {
+        let environment = Environment { lights_on: true, weather_seed: 0, has_bed: false };
+        let mut friend = Friend::born_at("duck", CreatureShapes::Duck(ColorWrapper::Red), 0);
+        friend.update_state(48 * 60 * 60 * 1000, &environment);
+        assert!(!*friend.alive());
+        let mut game_state = GameState::new(friend);
+
+        let new_friend = || Request::NewFriend { name: String::from("goose") };
+        assert!(matches!(new_friend().apply(&mut game_state), Response::Done));
+        assert_eq!(game_state.friend().name(), "goose");
+
+        // The second game to answer the death screen doesn't replace the new friend.
+        assert!(matches!(new_friend().apply(&mut game_state), Response::Error { .. }));
+        assert_eq!(game_state.friend().name(), "goose");
+    }
+}
@@ -0,0 +1,145 @@
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::clock;
+use crate::game_state::{GameState, SAVE_FILE_PATH};
+use crate::save_lock::SaveLock;
+use crate::utils::file_logging::log_to_file;
+use super::{DaemonClient, Request, Response, SOCKET_PATH};
+
+/// How often the daemon updates the game state.
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+/// How often the daemon saves the game, on top of saving after every change.
+const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Runs the daemon, which owns the game state and keeps it updated in the background.
+/// Other instances of the game talk to the daemon over a unix domain socket, so only the
+/// daemon ever writes the save file.
+pub fn run_daemon() -> std::io::Result<()> {
+    // Every other instance of the game would age along with a scaled clock, so the daemon always runs at the real time.
+    if clock::global().is_scaled() {
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "the daemon can't run with --time-scale"));
+    }
+
+    if DaemonClient::connect().is_ok() {
+        return Err(std::io::Error::new(std::io::ErrorKind::AlreadyExists, "the daemon is already running"));
+    }
+
+    // The socket of a daemon that didn't shut down cleanly is left behind, and has to be removed before binding.
+    match std::fs::remove_file(SOCKET_PATH) {
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => return Err(error),
+        _ => (),
+    }
+
+    // The daemon is the only one writing the save file, so it can't start while the game is being played without it.
+    let Some(_lock) = SaveLock::try_acquire(Path::new(SAVE_FILE_PATH))? else {
+        return Err(std::io::Error::new(std::io::ErrorKind::ResourceBusy, "the game is already running somewhere else"));
+    };
+
+    let game_state = Arc::new(Mutex::new(GameState::read_from_file(Path::new(SAVE_FILE_PATH))?));
+    let listener = UnixListener::bind(SOCKET_PATH)?;
+
+    let ticking_state = Arc::clone(&game_state);
+    thread::spawn(move || tick(ticking_state));
+
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let client_state = Arc::clone(&game_state);
+        thread::spawn(move || {
+            if let Err(error) = serve_client(stream, client_state) {
+                let _ = log_to_file(&format!("Daemon client failed: {error}"));
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Keeps the game state up to date, and saves it every now and then.
+fn tick(game_state: Arc<Mutex<GameState>>) {
+    let mut last_save = Instant::now();
+    loop {
+        thread::sleep(TICK_INTERVAL);
+
+        let mut game_state = game_state.lock().unwrap();
+        game_state.update();
+        if last_save.elapsed() >= SAVE_INTERVAL {
+            if let Err(error) = game_state.store_to_file(Path::new(SAVE_FILE_PATH)) {
+                let _ = log_to_file(&format!("Daemon failed to save the game: {error}"));
+            }
+            last_save = Instant::now();
+        }
+    }
+}
+
+/// Answers the requests of a single client, until it disconnects.
+/// <br>
+/// ## parameters:
+/// * `stream` - The connection to the client.
+/// * `game_state` - The game state shared by all clients.
+fn serve_client(stream: UnixStream, game_state: Arc<Mutex<GameState>>) -> std::io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    for line in BufReader::new(stream).lines() {
+        let response = match serde_json::from_str::<Request>(&line?) {
+            Ok(request) => handle_request(request, &game_state),
+            Err(error) => Response::Error { message: format!("invalid request: {error}") },
+        };
+
+        let mut response_line = serde_json::to_string(&response)?;
+        response_line.push('\n');
+        writer.write_all(response_line.as_bytes())?;
+    }
+
+    Ok(())
+}
+
+fn handle_request(request: Request, game_state: &Mutex<GameState>) -> Response {
+    let mut game_state = game_state.lock().unwrap();
+    game_state.update();
+
+    let changes_state = request.changes_state();
+    let response = request.apply(&mut game_state);
+    if changes_state {
+        if let Err(error) = game_state.store_to_file(Path::new(SAVE_FILE_PATH)) {
+            return Response::Error { message: format!("failed to save the game: {error}") };
+        }
+    }
+    response
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::net::UnixStream;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use crate::daemon::{DaemonClient, Request, Response};
+    use crate::friend::Friend;
+    use crate::game_state::GameState;
+    use crate::shapes::creatures::CreatureShapes;
+    use crate::utils::ColorWrapper;
+    use super::serve_client;
+
+    #[test]
+    fn daemon_answers_requests() {
+        let game_state = GameState::new(Friend::new("duck", CreatureShapes::Duck(ColorWrapper::Red)));
+        let (client_stream, server_stream) = UnixStream::pair().unwrap();
+        let server = thread::spawn(move || serve_client(server_stream, Arc::new(Mutex::new(game_state))));
+
+        let mut client = DaemonClient::from_stream(client_stream).unwrap();
+        match client.send(&Request::Status).unwrap() {
+            Response::Status(status) => assert_eq!(status.name, "duck"),
+            response => panic!("unexpected response: {response:?}"),
+        }
+        match client.send(&Request::State).unwrap() {
+            Response::State { game_state, .. } => assert_eq!(game_state.friend().name(), "duck"),
+            response => panic!("unexpected response: {response:?}"),
+        }
+
+        drop(client);
+        server.join().unwrap().unwrap();
+    }
+}
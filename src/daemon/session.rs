@@ -0,0 +1,412 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+use crate::achievements::Achievement;
+use crate::clock;
+use crate::game_state::{GameState, SAVE_FILE_PATH, SCALED_SAVE_FILE_PATH};
+use crate::save_lock::SaveLock;
+use super::{DaemonClient, Request, Response};
+
+/// How often the save file is checked for changes made by other processes.
+const RELOAD_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+const READ_ONLY_MESSAGE: &str = "the game is already running somewhere else, this instance is read-only";
+const DAEMON_LOST_MESSAGE: &str = "lost the connection to the daemon, try again in a moment";
+
+/// How a session is allowed to change the game.
+enum Access {
+    /// Every request is sent to the daemon, which owns the game state. The game state is only fetched
+    /// again when its revision changed, and the new achievements are only taken when `takes_achievements` is true.
+    Daemon {
+        client: DaemonClient,
+        revision: u64,
+        takes_achievements: bool,
+    },
+    /// This session holds the lock on the save file, and is the only one that writes it.
+    Owner { _lock: SaveLock },
+    /// Another instance holds the lock on the save file, so this session can only look at the game.
+    /// When `retry_lock` is true, the session takes over once the other instance has exited.
+    ReadOnly { retry_lock: bool },
+}
+
+/// The game state the player is looking at, together with the way it is kept up to date.
+/// When the daemon is running every request is sent to the daemon, otherwise the requests are
+/// applied to the local game state and it is saved to the save file directly.
+/// While the clock is scaled the daemon isn't used, and the game has a save file of its own.
+pub struct Session {
+    game_state: GameState,
+    access: Access,
+    save_file: PathBuf,
+    new_achievements: Vec<Achievement>,
+    /// Something the player should know about the session, like losing the connection to the daemon.
+    notice: Option<String>,
+    /// When the save file was last read or written by this session.
+    save_file_modified: Option<SystemTime>,
+    last_reload_check: Instant,
+}
+
+impl Session {
+    /// Connects to the daemon when it is running, otherwise the game is loaded from the save file.
+    pub fn open() -> std::io::Result<Self> {
+        if clock::global().is_scaled() {
+            return Self::scaled(Path::new(SCALED_SAVE_FILE_PATH), Path::new(SAVE_FILE_PATH));
+        }
+
+        if let Ok(daemon) = DaemonClient::connect() {
+            return Self::with_daemon(daemon, true);
+        }
+
+        Self::local(GameState::read_from_file(Path::new(SAVE_FILE_PATH))?)
+    }
+
+    /// Like `Session::open`, but never takes the lock on the save file. This is meant for
+    /// background tasks that only look at the game, so they don't make the game itself read-only.
+    pub fn open_read_only() -> std::io::Result<Self> {
+        let save_file = current_save_file();
+        if !clock::global().is_scaled() {
+            if let Ok(daemon) = DaemonClient::connect() {
+                return Self::with_daemon(daemon, false);
+            }
+        }
+
+        let game_state = GameState::read_from_file(&save_file)?;
+        Ok(Self::with_access(game_state, Access::ReadOnly { retry_lock: false }, save_file))
+    }
+
+    /// Creates a session that isn't connected to the daemon. The session is read-only when
+    /// another instance of the game holds the lock on the save file.
+    /// <br>
+    /// ## parameters:
+    /// * `game_state` - The game state to take care of.
+    pub fn local(game_state: GameState) -> std::io::Result<Self> {
+        Self::local_at(game_state, current_save_file())
+    }
+
+    /// Creates a session that isn't connected to the daemon, which saves the game to the given save file.
+    /// <br>
+    /// ## parameters:
+    /// * `game_state` - The game state to take care of.
+    /// * `save_file` - The path of the save file.
+    fn local_at(game_state: GameState, save_file: PathBuf) -> std::io::Result<Self> {
+        let access = match SaveLock::try_acquire(&save_file)? {
+            Some(lock) => Access::Owner { _lock: lock },
+            None => Access::ReadOnly { retry_lock: true },
+        };
+        Ok(Self::with_access(game_state, access, save_file))
+    }
+
+    /// Creates a session for a game with a scaled clock, which is saved to its own save file so the real
+    /// save file is left alone. The first time, the game starts with a copy of the pet in the real save file.
+    /// <br>
+    /// ## parameters:
+    /// * `save_file` - The save file of the game with a scaled clock.
+    /// * `real_save_file` - The save file of the game at the real time, which is only read.
+    fn scaled(save_file: &Path, real_save_file: &Path) -> std::io::Result<Self> {
+        let game_state = GameState::read_from_file(save_file)
+            .or_else(|_| GameState::read_from_file(real_save_file))?;
+        Self::local_at(game_state, save_file.to_path_buf())
+    }
+
+    /// Creates a session that sends every request to the daemon.
+    /// <br>
+    /// ## parameters:
+    /// * `client` - The connection to the daemon.
+    /// * `takes_achievements` - Whether this session announces new achievements to the player, which only the game itself does.
+    fn with_daemon(mut client: DaemonClient, takes_achievements: bool) -> std::io::Result<Self> {
+        let (game_state, revision) = fetch_state(&mut client)?;
+        let new_achievements = if takes_achievements { fetch_achievements(&mut client)? } else { Vec::new() };
+        Ok(Self {
+            game_state,
+            access: Access::Daemon { client, revision, takes_achievements },
+            save_file: PathBuf::from(SAVE_FILE_PATH),
+            new_achievements,
+            notice: None,
+            save_file_modified: None,
+            last_reload_check: Instant::now(),
+        })
+    }
+
+    fn with_access(game_state: GameState, access: Access, save_file: PathBuf) -> Self {
+        Self {
+            game_state,
+            access,
+            new_achievements: Vec::new(),
+            notice: None,
+            save_file_modified: GameState::save_file_modified(&save_file).ok(),
+            save_file,
+            last_reload_check: Instant::now(),
+        }
+    }
+
+    pub fn game_state(&self) -> &GameState {
+        &self.game_state
+    }
+
+    /// Returns true when this session can't change the game, because another instance owns the save file.
+    pub fn is_read_only(&self) -> bool {
+        matches!(self.access, Access::ReadOnly { .. })
+    }
+
+    /// Brings the game state up to date, by updating it locally or by getting the latest state from the daemon.
+    /// The game is reloaded when another process changed the save file, and a read-only session takes over
+    /// the save file once the instance that owned it has exited.
+    /// <br>
+    /// ## returns:
+    /// True when the game state was reloaded from the save file.
+    pub fn refresh(&mut self) -> std::io::Result<bool> {
+        let mut reloaded = false;
+        match &mut self.access {
+            Access::Daemon { client, revision, takes_achievements } => {
+                match fetch_changes(client, revision, *takes_achievements) {
+                    Ok(Some((game_state, new_achievements))) => {
+                        self.game_state = game_state;
+                        self.new_achievements.extend(new_achievements);
+                    },
+                    Ok(None) => (),
+                    Err(error) => reloaded = self.leave_daemon(&error),
+                }
+            },
+            Access::ReadOnly { retry_lock: true } => {
+                if let Some(lock) = SaveLock::try_acquire(&self.save_file)? {
+                    // The previous owner saved its changes when it exited, so continue from there.
+                    reloaded = self.reload();
+                    self.access = Access::Owner { _lock: lock };
+                }
+            },
+            _ => (),
+        }
+
+        if !matches!(self.access, Access::Daemon { .. }) {
+            if self.last_reload_check.elapsed() >= RELOAD_CHECK_INTERVAL {
+                self.last_reload_check = Instant::now();
+                if GameState::save_file_modified(&self.save_file).ok() != self.save_file_modified {
+                    reloaded |= self.reload();
+                }
+            }
+            self.game_state.update();
+        }
+        Ok(reloaded)
+    }
+
+    /// Stops using the daemon after the connection to it was lost, for example because the daemon was stopped.
+    /// The session continues with the save file the daemon left behind, read-only until it gets the lock on the save file.
+    /// <br>
+    /// ## parameters:
+    /// * `error` - The reason the connection was lost, which is told to the player.
+    ///
+    /// ## returns:
+    /// True when the game state was reloaded from the save file.
+    fn leave_daemon(&mut self, error: &std::io::Error) -> bool {
+        self.notice = Some(format!("Lost the connection to the daemon, continuing with the save file: {error}"));
+        self.access = Access::ReadOnly { retry_lock: true };
+        self.reload()
+    }
+
+    /// Reads the game state from the save file again, keeping the current state when the save file can't be read.
+    /// This happens when another process is still writing the file, so it will be tried again on the next check.
+    /// <br>
+    /// ## returns:
+    /// True when the game state was reloaded.
+    fn reload(&mut self) -> bool {
+        let modified = GameState::save_file_modified(&self.save_file).ok();
+        match GameState::read_from_file(&self.save_file) {
+            Ok(game_state) => {
+                self.game_state = game_state;
+                self.save_file_modified = modified;
+                true
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Writes the game state to the save file, and remembers when that happened
+    /// so our own changes aren't mistaken for changes by another process.
+    fn store(&mut self) -> std::io::Result<()> {
+        self.game_state.store_to_file(&self.save_file)?;
+        self.save_file_modified = GameState::save_file_modified(&self.save_file).ok();
+        Ok(())
+    }
+
+    /// Performs a request, either through the daemon or on the local game state.
+    /// The game is saved after every request that changes it.
+    /// <br>
+    /// ## parameters:
+    /// * `request` - The request to perform.
+    ///
+    /// ## returns:
+    /// The outcome of the request, which is `Response::Error` when a read-only session tries to change the game.
+    pub fn perform(&mut self, request: Request) -> std::io::Result<Response> {
+        let changes_state = request.changes_state();
+        match &mut self.access {
+            Access::Daemon { client, .. } => {
+                let response = match client.send(&request) {
+                    Ok(response) => response,
+                    Err(error) => {
+                        self.leave_daemon(&error);
+                        return Ok(Response::Error { message: String::from(DAEMON_LOST_MESSAGE) });
+                    },
+                };
+                if changes_state {
+                    self.refresh()?;
+                }
+                Ok(response)
+            },
+            Access::ReadOnly { .. } if changes_state => {
+                Ok(Response::Error { message: String::from(READ_ONLY_MESSAGE) })
+            },
+            Access::ReadOnly { .. } => Ok(request.apply(&mut self.game_state)),
+            Access::Owner { .. } => {
+                let response = request.apply(&mut self.game_state);
+                if changes_state {
+                    self.store()?;
+                }
+                Ok(response)
+            },
+        }
+    }
+
+    /// Saves the game, which is only done by the session that owns the save file.
+    pub fn save(&mut self) -> std::io::Result<()> {
+        match self.access {
+            Access::Owner { .. } => self.store(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns something the player should know about the session, if anything happened since this method was last called.
+    pub fn take_notice(&mut self) -> Option<String> {
+        self.notice.take()
+    }
+
+    /// Returns the achievements that were unlocked since this method was last called.
+    pub fn take_new_achievements(&mut self) -> Vec<Achievement> {
+        let mut new_achievements = std::mem::take(&mut self.new_achievements);
+        new_achievements.extend(self.game_state.take_new_achievements());
+        new_achievements
+    }
+}
+
+/// Returns the save file the game is played with, which is a separate one while the clock is scaled.
+fn current_save_file() -> PathBuf {
+    if clock::global().is_scaled() {
+        PathBuf::from(SCALED_SAVE_FILE_PATH)
+    } else {
+        PathBuf::from(SAVE_FILE_PATH)
+    }
+}
+
+/// Fetches the game state from the daemon when its revision changed, together with the new achievements
+/// when the session takes them.
+/// <br>
+/// ## parameters:
+/// * `client` - The connection to the daemon.
+/// * `revision` - The revision of the game state the session has, which is updated to the latest revision.
+/// * `takes_achievements` - Whether the session announces new achievements to the player.
+///
+/// ## returns:
+/// `None` when the game state didn't change.
+fn fetch_changes(client: &mut DaemonClient, revision: &mut u64, takes_achievements: bool)
+    -> std::io::Result<Option<(GameState, Vec<Achievement>)>> {
+    // Fetching the whole game state is only worth it when something changed.
+    if fetch_revision(client)? == *revision {
+        return Ok(None);
+    }
+
+    let (game_state, latest_revision) = fetch_state(client)?;
+    *revision = latest_revision;
+    let new_achievements = if takes_achievements { fetch_achievements(client)? } else { Vec::new() };
+    Ok(Some((game_state, new_achievements)))
+}
+
+fn fetch_revision(client: &mut DaemonClient) -> std::io::Result<u64> {
+    match client.send(&Request::Revision)? {
+        Response::Revision { revision } => Ok(revision),
+        response => Err(unexpected_response(response)),
+    }
+}
+
+fn fetch_state(client: &mut DaemonClient) -> std::io::Result<(GameState, u64)> {
+    match client.send(&Request::State)? {
+        Response::State { game_state, revision } => Ok((*game_state, revision)),
+        response => Err(unexpected_response(response)),
+    }
+}
+
+fn fetch_achievements(client: &mut DaemonClient) -> std::io::Result<Vec<Achievement>> {
+    match client.send(&Request::Achievements)? {
+        Response::Achievements { new_achievements } => Ok(new_achievements),
+        response => Err(unexpected_response(response)),
+    }
+}
+
+fn unexpected_response(response: Response) -> std::io::Error {
+    std::io::Error::other(format!("unexpected response from the daemon: {response:?}"))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+    use std::thread;
+    use crate::daemon::{DaemonClient, Request, Response, Session};
+    use crate::friend::Friend;
+    use crate::game_state::GameState;
+    use crate::save_lock::SaveLock;
+    use crate::shapes::creatures::CreatureShapes;
+    use crate::utils::ColorWrapper;
+
+    #[test]
+    fn scaled_sessions_leave_the_real_save_file_alone() {
+        let dir = std::env::temp_dir().join(format!("termagotchi-scaled-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let real_save_file = dir.join("save-file.txt");
+        let save_file = dir.join("save-file-scaled.txt");
+        GameState::new(Friend::new("duck", CreatureShapes::Duck(ColorWrapper::Red)))
+            .store_to_file(&real_save_file)
+            .unwrap();
+        let real_save = fs::read(&real_save_file).unwrap();
+
+        // The scaled game starts with the pet of the real save file, but only writes and locks its own save file.
+        let mut session = Session::scaled(&save_file, &real_save_file).unwrap();
+        assert_eq!(session.game_state().friend().name(), "duck");
+        assert!(!session.is_read_only());
+        session.perform(Request::Play).unwrap();
+        session.save().unwrap();
+        assert!(SaveLock::try_acquire(&real_save_file).unwrap().is_some());
+        drop(session);
+
+        let real_save_after = fs::read(&real_save_file).unwrap();
+        let scaled_game_state = GameState::read_from_file(&save_file).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(real_save_after, real_save);
+        assert_eq!(scaled_game_state.friend().name(), "duck");
+    }
+
+    #[test]
+    fn sessions_keep_going_when_the_daemon_stops() {
+        let (client_stream, daemon_stream) = UnixStream::pair().unwrap();
+        // A daemon that only answers the requests of a new session, and then stops.
+        let daemon = thread::spawn(move || {
+            let mut writer = daemon_stream.try_clone().unwrap();
+            let mut requests = BufReader::new(daemon_stream).lines();
+            let game_state = GameState::new(Friend::new("duck", CreatureShapes::Duck(ColorWrapper::Red)));
+            for response in [
+                Response::State { game_state: Box::new(game_state), revision: 1 },
+                Response::Achievements { new_achievements: Vec::new() },
+            ] {
+                requests.next().unwrap().unwrap();
+                writer.write_all(format!("{}\n", serde_json::to_string(&response).unwrap()).as_bytes()).unwrap();
+            }
+        });
+
+        let mut session = Session::with_daemon(DaemonClient::from_stream(client_stream).unwrap(), true).unwrap();
+        daemon.join().unwrap();
+        assert!(!session.is_read_only());
+
+        session.refresh().unwrap();
+        assert!(session.is_read_only());
+        assert!(session.take_notice().is_some());
+        assert_eq!(session.game_state().friend().name(), "duck");
+        assert!(matches!(session.perform(Request::Play).unwrap(), Response::Error { .. }));
+    }
+}
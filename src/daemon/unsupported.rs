@@ -0,0 +1,22 @@
+use super::{Request, Response};
+
+fn unsupported() -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::Unsupported, "the daemon is only supported on unix systems")
+}
+
+/// The daemon uses unix domain sockets, so there never is a daemon to connect to on other systems.
+pub struct DaemonClient;
+
+impl DaemonClient {
+    pub fn connect() -> std::io::Result<Self> {
+        Err(unsupported())
+    }
+
+    pub fn send(&mut self, _request: &Request) -> std::io::Result<Response> {
+        Err(unsupported())
+    }
+}
+
+pub fn run_daemon() -> std::io::Result<()> {
+    Err(unsupported())
+}
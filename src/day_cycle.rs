@@ -0,0 +1,66 @@
+use chrono::{Local, TimeZone, Timelike};
+use ratatui::style::Color;
+
+/// The local hour at which the pet gets sleepy and falls asleep on its own.
+pub const BEDTIME_HOUR: u32 = 21;
+/// The local hour after which keeping the pet awake starts costing it joy.
+pub const LATE_HOUR: u32 = 23;
+/// The local hour at which a sleeping pet wakes up by itself.
+pub const WAKE_UP_HOUR: u32 = 7;
+
+/// The phases of a day, based on the local time of the player.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DayPhase {
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+}
+
+impl DayPhase {
+    /// Returns the phase of the day for the given utc timestamp, using the local timezone.
+    /// <br>
+    /// ## parameters:
+    /// * `timestamp_millis` - The utc time in millis to get the phase of the day for.
+    pub fn from_timestamp(timestamp_millis: i64) -> Self {
+        match local_hour(timestamp_millis) {
+            hour if !(WAKE_UP_HOUR..BEDTIME_HOUR).contains(&hour) => DayPhase::Night,
+            hour if hour < 12 => DayPhase::Morning,
+            hour if hour < 18 => DayPhase::Afternoon,
+            _ => DayPhase::Evening,
+        }
+    }
+
+    /// The background color of the sky during this phase of the day.
+    pub fn sky_color(&self) -> Color {
+        match self {
+            DayPhase::Morning => Color::Rgb(40, 70, 110),
+            DayPhase::Afternoon => Color::Rgb(30, 90, 150),
+            DayPhase::Evening => Color::Rgb(80, 45, 70),
+            DayPhase::Night => Color::Black,
+        }
+    }
+}
+
+/// Returns the hour of the day (0-23) in the local timezone for the given utc timestamp.
+pub fn local_hour(timestamp_millis: i64) -> u32 {
+    Local.timestamp_millis_opt(timestamp_millis)
+        .earliest()
+        .map(|time| time.hour())
+        .unwrap_or(12)
+}
+
+/// Returns how far the local day has progressed for the given utc timestamp,
+/// as a value in range `0.0..1.0` where `0.0` is midnight.
+pub fn day_progress(timestamp_millis: i64) -> f64 {
+    Local.timestamp_millis_opt(timestamp_millis)
+        .earliest()
+        .map(|time| f64::from(time.num_seconds_from_midnight()) / 86400.0)
+        .unwrap_or(0.5)
+}
+
+/// Returns true when it is past the pet's bedtime for long enough that staying awake hurts its joy.
+pub fn is_late(timestamp_millis: i64) -> bool {
+    let hour = local_hour(timestamp_millis);
+    !(WAKE_UP_HOUR..LATE_HOUR).contains(&hour)
+}
@@ -0,0 +1,28 @@
+use crate::weather::Weather;
+
+/// The surroundings of the friend that are not part of the friend itself,
+/// but do influence how its stats change over time.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Environment {
+    pub lights_on: bool,
+    pub weather_seed: u64,
+    pub has_bed: bool,
+}
+
+impl Environment {
+    /// Returns the weather in the friend's surroundings at the given moment.
+    /// <br>
+    /// ## parameters:
+    /// * `timestamp_millis` - The utc time in millis to get the weather for.
+    pub fn weather_at(&self, timestamp_millis: i64) -> Weather {
+        Weather::at(timestamp_millis, self.weather_seed)
+    }
+
+    /// Returns true when it is hot in the friend's surroundings at the given moment.
+    /// <br>
+    /// ## parameters:
+    /// * `timestamp_millis` - The utc time in millis to check the temperature for.
+    pub fn is_hot_at(&self, timestamp_millis: i64) -> bool {
+        Weather::is_hot(timestamp_millis, self.weather_seed)
+    }
+}
@@ -1,8 +1,9 @@
-use rand::prelude::*;
+use serde::{Deserialize, Serialize};
 
-const FOOD_COUNT: u8 = 3;
+/// Every kind of food there is.
+pub const FOODS: [Food; 3] = [Food::Soup, Food::Cookie, Food::Burger];
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Food {
     Soup,
     Cookie,
@@ -17,13 +18,5 @@ impl Food {
             Food::Burger => 40,
         }
     }
-    
-    pub fn new_random() -> Self {
-        let mut rng = thread_rng();
-        match rng.gen_range(0..FOOD_COUNT) {
-            0 => Food::Soup,
-            1 => Food::Cookie,
-            _ => Food::Burger,
-        }
-    }
+
 }
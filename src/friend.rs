@@ -1,11 +1,32 @@
 use crate::food::Food;
-use crate::utils::Stat;
+use crate::utils::{ColorWrapper, Stat};
 use serde::{Deserialize, Serialize};
-use chrono::Utc;
+use crate::clock;
 use crate::shapes::creatures::CreatureShapes;
 use crate::shapes::{GrowthStageShapes, PixelVectorShape};
+use crate::day_cycle::{self, DayPhase};
+use crate::environment::Environment;
+use crate::weather::Weather;
 
 const MINUTE_MILLIS: i64 = 1000 * 60;
+/// How often the food of the friend goes down.
+pub const FOOD_DECAY_MILLIS: i64 = 16 * MINUTE_MILLIS;
+/// How often the energy of the friend changes, going down while awake and up while asleep.
+pub const ENERGY_DECAY_MILLIS: i64 = 14 * MINUTE_MILLIS;
+/// How often the joy of the friend goes down.
+pub const JOY_DECAY_MILLIS: i64 = 18 * MINUTE_MILLIS;
+/// How often the health of the friend goes down, while it is recovering from eating or playing.
+const HEALTH_DECAY_MILLIS: i64 = MINUTE_MILLIS;
+/// The friend dies when its food, joy and health added together drop below this.
+pub const MIN_STATS_SUM: u32 = 15;
+/// The friend dies when this many of its stats are empty at the same time.
+pub const MAX_EMPTY_STATS: u8 = 2;
+/// How long the friend can sleep before it wakes up on its own.
+pub const MAX_SLEEP_MILLIS: i64 = 12 * 60 * MINUTE_MILLIS;
+/// How much joy the friend gets from being petted.
+const PET_JOY: u32 = 5;
+/// How long the friend has to wait before it enjoys being petted again.
+pub const PET_COOLDOWN_MILLIS: i64 = MINUTE_MILLIS;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum GrowthStage {
@@ -15,6 +36,28 @@ pub enum GrowthStage {
     Adult,
 }
 
+/// The different stats of a friend.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StatKind {
+    Food,
+    Joy,
+    Energy,
+    Health,
+}
+
+impl StatKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatKind::Food => "food",
+            StatKind::Joy => "joy",
+            StatKind::Energy => "energy",
+            StatKind::Health => "health",
+        }
+    }
+}
+
+pub const STAT_KINDS: [StatKind; 4] = [StatKind::Food, StatKind::Joy, StatKind::Energy, StatKind::Health];
+
 impl GrowthStage {
     /// Upgrades self to the next logical growth stage.
     pub fn next_stage(&mut self) {
@@ -26,6 +69,26 @@ impl GrowthStage {
             GrowthStage::Adult => (),
         }
     }
+
+    /// Returns how long after the friend was created it grows out of this stage,
+    /// or `None` when it is fully grown.
+    pub fn grows_after_millis(&self) -> Option<i64> {
+        match self {
+            GrowthStage::Egg => Some(300000),     // 5 minutes
+            GrowthStage::Baby => Some(18000000),  // 5 hours
+            GrowthStage::Kid => Some(86400000),   // 24 hours
+            GrowthStage::Adult => None,
+        }
+    }
+
+    /// Returns the width and height of the built-in sprites of this stage, sprites of species packs can't be larger.
+    pub fn sprite_size(&self) -> u32 {
+        match self {
+            GrowthStage::Egg | GrowthStage::Baby => 10,
+            GrowthStage::Kid => 15,
+            GrowthStage::Adult => 25,
+        }
+    }
 }
 
 
@@ -45,13 +108,28 @@ pub struct Friend {
     growth_stage: GrowthStage,
     asleep: bool,
     asleep_since: Option<i64>,
+    #[serde(default)]
+    kept_awake: bool,
+    #[serde(default)]
+    last_time_food_empty: Option<i64>,
+    #[serde(default)]
+    last_time_petted: Option<i64>,
     alive: bool,
     time_created: i64,
 }
 
 impl Friend {
     pub fn new(name: &str, shape: CreatureShapes) -> Self {
-        let now = Utc::now().timestamp_millis();
+        Self::born_at(name, shape, clock::global().now_millis())
+    }
+
+    /// Creates a new friend that came into the world at the given time.
+    /// <br>
+    /// ## parameters:
+    /// * `name` - The name of the friend.
+    /// * `shape` - The shape the friend grows into.
+    /// * `now` - The current utc time in millis.
+    pub fn born_at(name: &str, shape: CreatureShapes, now: i64) -> Self {
         Self {
             name: String::from(name),
             food: Stat::new(50).unwrap(),
@@ -67,48 +145,77 @@ impl Friend {
             growth_stage: GrowthStage::Egg,
             asleep: false,
             asleep_since: None,
+            kept_awake: false,
+            last_time_food_empty: None,
+            last_time_petted: None,
             alive: true,
             time_created: now,
         }
     }
 
     /// Updates this Friend's state for each minute passed since last update <br>
-    pub fn update_state(&mut self, now: i64) {
+    /// ## parameters:
+    /// * `now` - The current utc time in millis.
+    /// * `environment` - The surroundings of the friend, like the lights and the weather.
+    pub fn update_state(&mut self, now: i64, environment: &Environment) {
+        if self.growth_stage == GrowthStage::Egg {
+            self.update_egg_warmth(now, environment);
+            if !self.alive {
+                return;
+            }
+        }
+
         self.update_growth_stage(now);
         
         if self.growth_stage != GrowthStage::Egg {
-            self.update_stats(now);
+            self.update_stats(now, environment);
             // self.update_asleep_status(now);
             self.update_alive_status();
         }
     }
     
-    fn update_stats(&mut self, now: i64) {
-        let food_offset_minutes = 16 * MINUTE_MILLIS;
-        let energy_offset_minutes = 14 * MINUTE_MILLIS;
-        let joy_offset_minutes = 18 * MINUTE_MILLIS;
-        let health_offset_minutes = MINUTE_MILLIS;
+    fn update_stats(&mut self, now: i64, environment: &Environment) {
+        let food_offset_minutes = FOOD_DECAY_MILLIS;
+        let energy_offset_minutes = ENERGY_DECAY_MILLIS;
+        let joy_offset_minutes = JOY_DECAY_MILLIS;
+        let health_offset_minutes = HEALTH_DECAY_MILLIS;
 
         // Use while loops instead of if statements to account for loading from file
         // when we might have been away for more than a single minute.
         while now - self.last_time_lower_food >= food_offset_minutes {
             self.food.subtract(1);
             self.last_time_lower_food += food_offset_minutes;
+
+            if self.food.is_min() {
+                self.last_time_food_empty = Some(self.last_time_lower_food);
+            }
         }
 
         while now - self.last_time_lower_energy >= energy_offset_minutes {
-            match self.asleep {
-                true => self.energy.add(3),
-                false => self.energy.subtract(1),
-            }
+            let previous_tick = self.last_time_lower_energy;
             self.last_time_lower_energy += energy_offset_minutes;
+
+            let bed_bonus = u32::from(environment.has_bed);
+            match (self.asleep, environment.lights_on) {
+                (true, false) => self.energy.add(3 + bed_bonus),
+                (true, true) => self.energy.add(1 + bed_bonus),
+                // The heat makes the friend get tired a lot faster.
+                (false, _) if environment.is_hot_at(self.last_time_lower_energy) => self.energy.subtract(2),
+                (false, _) => self.energy.subtract(1),
+            }
             
+            self.update_bedtime_status(previous_tick, self.last_time_lower_energy);
             self.update_asleep_status(now);
         }
 
         while now - self.last_time_lower_joy >= joy_offset_minutes {
             self.joy.subtract(1);
             self.last_time_lower_joy += joy_offset_minutes;
+
+            let is_raining = environment.weather_at(self.last_time_lower_joy) == Weather::Rainy;
+            if is_raining && !self.asleep && !self.shape.likes_water() {
+                self.joy.subtract(1);
+            }
         }
         
         while now - self.last_time_lower_health >= health_offset_minutes {
@@ -131,16 +238,55 @@ impl Friend {
         }
         
         if let Some(start_sleeping) = self.asleep_since {
-            if now - start_sleeping > MINUTE_MILLIS * 60 * 12 {
-                self.asleep = false;
-                self.asleep_since = None;
+            if now - start_sleeping > MAX_SLEEP_MILLIS {
+                self.wake_up();
             }
         }
     }
 
+    /// Applies the bedtime rules of the day/night cycle for a single tick. At night the friend falls
+    /// asleep on its own unless the player kept it awake, and it wakes up by itself in the morning.
+    /// Keeping the friend awake too late costs it some joy.
+    /// <br>
+    /// ## parameters:
+    /// * `previous_tick` - The utc time in millis of the previous tick.
+    /// * `tick` - The utc time in millis of the tick that should be processed.
+    fn update_bedtime_status(&mut self, previous_tick: i64, tick: i64) {
+        let previous_phase = DayPhase::from_timestamp(previous_tick);
+        let phase = DayPhase::from_timestamp(tick);
+
+        if phase != DayPhase::Night {
+            self.kept_awake = false;
+            if previous_phase == DayPhase::Night && self.asleep {
+                self.wake_up();
+            }
+            return;
+        }
+
+        if !self.asleep && !self.kept_awake {
+            self.fall_asleep(tick);
+        }
+
+        if !self.asleep && day_cycle::is_late(tick) {
+            self.joy.subtract(1);
+        }
+    }
+
+    /// An egg that is left in the dark while it snows before it hatches freezes and dies.
+    /// <br>
+    /// ## parameters:
+    /// * `now` - The current utc time in millis.
+    /// * `environment` - The surroundings of the egg, like the lights and the weather.
+    fn update_egg_warmth(&mut self, now: i64, environment: &Environment) {
+        let hatches_at = self.time_created + GrowthStage::Egg.grows_after_millis().unwrap_or_default();
+        if now < hatches_at && !environment.lights_on && environment.weather_at(now) == Weather::Snowy {
+            self.alive = false;
+        }
+    }
+
     fn update_alive_status(&mut self) {
         let stats_sum = self.food.value() + self.joy.value() + self.health.value();
-        if stats_sum < 15 {
+        if stats_sum < MIN_STATS_SUM {
             self.alive = false;
         }
         
@@ -150,7 +296,7 @@ impl Friend {
                 counter += 1;
             }
         }
-        if counter >= 2 {
+        if counter >= MAX_EMPTY_STATS {
             self.alive = false;
         }
         
@@ -160,14 +306,7 @@ impl Friend {
     }
 
     fn update_growth_stage(&mut self, now: i64) {
-        let growth_delay = match self.growth_stage {
-            GrowthStage::Egg => Some(300000),     // 5 minutes
-            GrowthStage::Baby => Some(18000000),  // 5 hours
-            GrowthStage::Kid => Some(86400000),   // 24 hours
-            GrowthStage::Adult => None,
-        };
-        
-        if let Some(growth_delay) = growth_delay {
+        if let Some(growth_delay) = self.growth_stage.grows_after_millis() {
             if now - self.time_created > growth_delay {
                 self.growth_stage.next_stage();
             }
@@ -187,20 +326,34 @@ impl Friend {
         self.health_decrease_time_left += (food.points() / 3) as i64 * MINUTE_MILLIS;
     }
 
-    pub fn toggle_sleep(&mut self) {
+    /// Puts the friend to bed, or wakes it up when it is asleep.
+    /// <br>
+    /// ## parameters:
+    /// * `now` - The current utc time in millis.
+    pub fn toggle_sleep(&mut self, now: i64) {
         if self.growth_stage == GrowthStage::Egg {
             return;
         }
 
-        self.asleep = !self.asleep;
-        
         if self.asleep {
-            let now = Utc::now().timestamp_millis();
-            self.asleep_since = Some(now);
+            self.wake_up();
+            // Waking the friend up at night means the player wants to keep it awake.
+            self.kept_awake = DayPhase::from_timestamp(now) == DayPhase::Night;
         } else {
-            self.asleep_since = None;
+            self.fall_asleep(now);
         }
     }
+
+    fn fall_asleep(&mut self, now: i64) {
+        self.asleep = true;
+        self.asleep_since = Some(now);
+        self.kept_awake = false;
+    }
+
+    fn wake_up(&mut self) {
+        self.asleep = false;
+        self.asleep_since = None;
+    }
     
     pub fn play(&mut self) {
         if self.growth_stage != GrowthStage::Egg {
@@ -209,6 +362,27 @@ impl Friend {
         }
     }
 
+    /// Pets the friend, which gives it a little joy. Petting it again right away doesn't do anything,
+    /// the friend first needs a moment to enjoy it.
+    /// <br>
+    /// ## parameters:
+    /// * `now` - The current utc time in millis.
+    ///
+    /// ## returns:
+    /// True when the friend enjoyed being petted.
+    pub fn pet(&mut self, now: i64) -> bool {
+        if self.growth_stage == GrowthStage::Egg {
+            return false;
+        }
+        if self.last_time_petted.is_some_and(|petted| now - petted < PET_COOLDOWN_MILLIS) {
+            return false;
+        }
+
+        self.last_time_petted = Some(now);
+        self.joy.add(PET_JOY);
+        true
+    }
+
     pub fn take_medicine(&mut self) {
         if self.growth_stage != GrowthStage::Egg {
             self.health.add(40);
@@ -219,6 +393,11 @@ impl Friend {
         self.asleep
     }
 
+    /// Returns true when it is night time and the friend is still awake.
+    pub fn is_sleepy(&self, now: i64) -> bool {
+        !self.asleep && DayPhase::from_timestamp(now) == DayPhase::Night
+    }
+
     pub fn food(&self) -> &Stat {
         &self.food
     }
@@ -235,12 +414,63 @@ impl Friend {
         &self.health
     }
     
+    pub fn stat(&self, kind: StatKind) -> &Stat {
+        match kind {
+            StatKind::Food => &self.food,
+            StatKind::Joy => &self.joy,
+            StatKind::Energy => &self.energy,
+            StatKind::Health => &self.health,
+        }
+    }
+
+    /// Works out when the given stat will drop to the threshold, using the decay intervals of the stats.
+    /// Extra decay caused by the weather isn't taken into account.
+    /// <br>
+    /// ## parameters:
+    /// * `kind` - The stat to check.
+    /// * `threshold` - The value at which the stat becomes critical.
+    ///
+    /// ## returns:
+    /// * `Some(i64)` - The utc time in millis at which the stat reaches the threshold,
+    ///   which is in the past when the stat already is critical.
+    /// * `None` - When the stat isn't going down right now.
+    pub fn critical_time(&self, kind: StatKind, threshold: u32) -> Option<i64> {
+        if self.growth_stage == GrowthStage::Egg {
+            return None;
+        }
+
+        let value = self.stat(kind).value();
+        let drops = i64::from(value.saturating_sub(threshold));
+        match kind {
+            StatKind::Food => Some(self.last_time_lower_food + drops * FOOD_DECAY_MILLIS),
+            StatKind::Joy => Some(self.last_time_lower_joy + drops * JOY_DECAY_MILLIS),
+            StatKind::Energy if self.asleep && value > threshold => None,
+            StatKind::Energy => Some(self.last_time_lower_energy + drops * ENERGY_DECAY_MILLIS),
+            StatKind::Health if self.health_decrease_time_left < drops * HEALTH_DECAY_MILLIS => None,
+            StatKind::Health => Some(self.last_time_lower_health + drops * HEALTH_DECAY_MILLIS),
+        }
+    }
+
+    /// Returns the stat that will become critical first, together with the moment it does.
+    /// <br>
+    /// ## parameters:
+    /// * `threshold` - The value at which a stat becomes critical.
+    pub fn next_critical(&self, threshold: u32) -> Option<(StatKind, i64)> {
+        STAT_KINDS.iter()
+            .filter_map(|kind| self.critical_time(*kind, threshold).map(|time| (*kind, time)))
+            .min_by_key(|(_, time)| *time)
+    }
+
     pub fn growth_stage(&self) -> GrowthStage {
         self.growth_stage
     }
     
     pub fn get_shape_wrapper(&self) -> ShapeWrapper {
         let color = self.shape.get_color();
+        if let (CreatureShapes::Custom(id, _), false) = (&self.shape, self.growth_stage == GrowthStage::Adult) {
+            return ShapeWrapper::Growing(GrowthStageShapes::Custom(id.clone(), self.growth_stage, color));
+        }
+
         match self.growth_stage {
             GrowthStage::Egg => ShapeWrapper::Growing(GrowthStageShapes::Egg(color)),
             GrowthStage::Baby => ShapeWrapper::Growing(GrowthStageShapes::Baby(color)),
@@ -262,6 +492,20 @@ impl Friend {
     }
     
     pub fn time_created(&self) -> i64 { self.time_created }
+
+    /// Returns the utc time in millis since which the friend's food hasn't dropped to zero.
+    pub fn well_fed_since(&self) -> i64 {
+        self.last_time_food_empty.unwrap_or(self.time_created)
+    }
+
+    pub fn shape(&self) -> &CreatureShapes {
+        &self.shape
+    }
+
+    /// Gives the friend a new color, while keeping its shape.
+    pub fn repaint(&mut self, color: ColorWrapper) {
+        self.shape = self.shape.with_color(color);
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -273,28 +517,76 @@ pub enum ShapeWrapper {
 
 #[cfg(test)]
 mod tests {
-    use chrono::Utc;
-    use crate::friend::{Friend, GrowthStage, MINUTE_MILLIS};
+    use chrono::{Local, TimeZone};
+    use crate::environment::Environment;
+    use crate::friend::{Friend, GrowthStage, StatKind, MINUTE_MILLIS, PET_COOLDOWN_MILLIS, PET_JOY};
     use crate::shapes::creatures::CreatureShapes;
     use crate::utils::{ColorWrapper, Stat};
+    use crate::weather::Weather;
 
-    #[test]
-    fn friend_auto_wakeup_test() {
-        let max_sleep_time = MINUTE_MILLIS * 60 * 12;
-        let now = Utc::now().timestamp_millis();
+    /// Returns the utc time in millis for the given local day and hour, so the tests do not
+    /// depend on the time of day they are run at.
+    fn local_millis(day: u32, hour: u32) -> i64 {
+        Local.with_ymd_and_hms(2024, 6, day, hour, 0, 0)
+            .earliest()
+            .unwrap()
+            .timestamp_millis()
+    }
+
+    fn environment(lights_on: bool) -> Environment {
+        Environment {
+            lights_on,
+            weather_seed: 0,
+            has_bed: false,
+        }
+    }
+
+    fn adult_friend(now: i64) -> Friend {
         let mut friend = Friend::new(
-            "test-friend", 
+            "test-friend",
             CreatureShapes::Squid(ColorWrapper::Green)
         );
-        
+
         friend.growth_stage = GrowthStage::Adult;
         friend.food = Stat::new(100).unwrap();
         friend.joy = Stat::new(100).unwrap();
         friend.energy = Stat::new(100).unwrap();
         friend.health = Stat::new(100).unwrap();
-        friend.toggle_sleep();
+        friend.last_time_lower_food = now;
+        friend.last_time_lower_joy = now;
+        friend.last_time_lower_energy = now;
+        friend.last_time_lower_health = now;
+        friend.time_created = now;
+        friend
+    }
+
+    #[test]
+    fn predicts_when_stats_become_critical() {
+        let now = local_millis(3, 12);
+        let mut friend = adult_friend(now);
+        friend.food = Stat::new(30).unwrap();
+        friend.joy = Stat::new(30).unwrap();
+
+        // Food goes down every 16 minutes and joy every 18, so food is the first to run low.
+        assert_eq!(friend.critical_time(StatKind::Food, 20), Some(now + 10 * 16 * MINUTE_MILLIS));
+        assert_eq!(friend.critical_time(StatKind::Joy, 20), Some(now + 10 * 18 * MINUTE_MILLIS));
+        assert_eq!(friend.next_critical(20), Some((StatKind::Food, now + 10 * 16 * MINUTE_MILLIS)));
+
+        // Health only goes down while the friend is recovering from a meal.
+        assert_eq!(friend.critical_time(StatKind::Health, 20), None);
+
+        friend.update_state(now + 10 * 16 * MINUTE_MILLIS, &environment(true));
+        assert_eq!(friend.food().value(), 20);
+    }
+
+    #[test]
+    fn friend_auto_wakeup_test() {
+        let max_sleep_time = MINUTE_MILLIS * 60 * 12;
+        let now = local_millis(3, 8);
+        let mut friend = adult_friend(now);
+        friend.fall_asleep(now);
         
-        friend.update_state(now + max_sleep_time + 100);
+        friend.update_state(now + max_sleep_time + 100, &environment(false));
         
         assert!(!friend.asleep);
         assert_eq!(None, friend.asleep_since);
@@ -303,22 +595,136 @@ mod tests {
     #[test]
     fn lower_energy_after_auto_wakeup() {
         let max_sleep_time = MINUTE_MILLIS * 60 * 12;
-        let now = Utc::now().timestamp_millis();
-        let mut friend = Friend::new(
-            "test-friend",
-            CreatureShapes::Squid(ColorWrapper::Green)
-        );
-
-        friend.food = Stat::new(100).unwrap();
-        friend.joy = Stat::new(100).unwrap();
-        friend.energy = Stat::new(100).unwrap();
-        friend.health = Stat::new(100).unwrap();
-        friend.toggle_sleep();
+        let now = local_millis(3, 8);
+        let mut friend = adult_friend(now);
+        friend.energy = Stat::new(20).unwrap();
+        friend.fall_asleep(now);
         
-        friend.update_state(now + 10 * max_sleep_time); // Make sure the termagotchi energy is at zero.
+        // Make sure the termagotchi energy is at zero, without the friend going to bed at night.
+        friend.update_state(now + max_sleep_time + 30 * MINUTE_MILLIS, &environment(false));
         
         assert!(!friend.asleep);
         assert_eq!(None, friend.asleep_since);
         assert_eq!(0, friend.energy.value());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn falls_asleep_at_bedtime() {
+        let now = local_millis(3, 20);
+        let mut friend = adult_friend(now);
+
+        friend.update_state(local_millis(3, 22), &environment(false));
+
+        assert!(friend.asleep);
+    }
+
+    #[test]
+    fn wakes_up_in_the_morning() {
+        let now = local_millis(3, 22);
+        let mut friend = adult_friend(now);
+        friend.fall_asleep(now);
+
+        friend.update_state(local_millis(4, 8), &environment(false));
+
+        assert!(!friend.asleep);
+        assert_eq!(None, friend.asleep_since);
+    }
+
+    #[test]
+    fn kept_awake_too_late_loses_joy() {
+        let now = local_millis(3, 23);
+        let mut sleeping_friend = adult_friend(now);
+        sleeping_friend.fall_asleep(now);
+        let mut awake_friend = adult_friend(now);
+        awake_friend.kept_awake = true;
+
+        sleeping_friend.update_state(local_millis(4, 2), &environment(false));
+        awake_friend.update_state(local_millis(4, 2), &environment(false));
+
+        assert!(!awake_friend.asleep);
+        assert!(awake_friend.joy.value() < sleeping_friend.joy.value());
+    }
+
+    #[test]
+    fn sleeping_with_lights_on_recovers_less_energy() {
+        let now = local_millis(3, 22);
+        let mut dark_room_friend = adult_friend(now);
+        dark_room_friend.energy = Stat::new(0).unwrap();
+        dark_room_friend.fall_asleep(now);
+        let mut lit_room_friend = dark_room_friend.clone();
+
+        dark_room_friend.update_state(local_millis(4, 1), &environment(false));
+        lit_room_friend.update_state(local_millis(4, 1), &environment(true));
+
+        assert!(lit_room_friend.energy.value() < dark_room_friend.energy.value());
+    }
+
+    #[test]
+    fn rain_only_bothers_creatures_that_dislike_water() {
+        let now = local_millis(3, 8);
+        let later = local_millis(3, 20);
+        let rainy_seed = (0..)
+            .find(|seed| (0..12).any(|hour| Weather::at(now + hour * 60 * MINUTE_MILLIS, *seed) == Weather::Rainy))
+            .unwrap();
+        let environment = Environment { lights_on: true, weather_seed: rainy_seed, has_bed: false };
+
+        let mut mouse = adult_friend(now);
+        mouse.shape = CreatureShapes::Mouse(ColorWrapper::Green);
+        let mut duck = adult_friend(now);
+        duck.shape = CreatureShapes::Duck(ColorWrapper::Green);
+
+        mouse.update_state(later, &environment);
+        duck.update_state(later, &environment);
+
+        assert!(mouse.joy.value() < duck.joy.value());
+    }
+
+    #[test]
+    fn sleeping_in_a_bed_recovers_more_energy() {
+        let now = local_millis(3, 22);
+        let mut floor_friend = adult_friend(now);
+        floor_friend.energy = Stat::new(0).unwrap();
+        floor_friend.fall_asleep(now);
+        let mut bed_friend = floor_friend.clone();
+        let bed_environment = Environment { has_bed: true, ..environment(false) };
+
+        floor_friend.update_state(local_millis(4, 1), &environment(false));
+        bed_friend.update_state(local_millis(4, 1), &bed_environment);
+
+        assert!(bed_friend.energy.value() > floor_friend.energy.value());
+    }
+
+    #[test]
+    fn petting_has_a_cooldown() {
+        let now = local_millis(3, 12);
+        let mut friend = adult_friend(now);
+        friend.joy = Stat::new(50).unwrap();
+
+        assert!(friend.pet(now));
+        assert!(!friend.pet(now + PET_COOLDOWN_MILLIS / 2));
+        assert_eq!(friend.joy.value(), 50 + PET_JOY);
+
+        assert!(friend.pet(now + PET_COOLDOWN_MILLIS));
+        assert_eq!(friend.joy.value(), 50 + 2 * PET_JOY);
+    }
+
+    #[test]
+    fn eggs_stay_warm_with_the_lights_on() {
+        // The start of the first snowy period in January.
+        let snowy = (0..)
+            .map(|period| 1_704_153_600_000 + period * 3 * 60 * MINUTE_MILLIS)
+            .find(|timestamp| environment(false).weather_at(*timestamp) == Weather::Snowy)
+            .unwrap();
+        let hatches_at = snowy + GrowthStage::Egg.grows_after_millis().unwrap();
+
+        let mut egg = Friend::born_at("egg", CreatureShapes::Duck(ColorWrapper::Red), snowy);
+        egg.update_state(snowy + MINUTE_MILLIS, &environment(true));
+        assert!(*egg.alive());
+        assert_eq!(egg.growth_stage, GrowthStage::Egg);
+
+        // The egg already hatched by the time the lights went off.
+        egg.update_state(hatches_at + MINUTE_MILLIS, &environment(false));
+        assert!(*egg.alive());
+        assert_ne!(egg.growth_stage, GrowthStage::Egg);
+    }
+}
@@ -0,0 +1,209 @@
+use std::time::{Duration, Instant};
+
+/// The time between two updates of the game logic. The pet takes one step every tick.
+pub const LOGIC_TICK: Duration = Duration::from_millis(500);
+/// The most ticks that are caught up on at once, so the pet doesn't race across the playground
+/// after the game couldn't update for a while, for example while the shop was open.
+const MAX_CATCH_UP_TICKS: u32 = 4;
+/// The frame rate can't be set higher than this.
+pub const MAX_FPS: u32 = 60;
+/// The time between two frames while the game is in low power mode.
+pub const LOW_POWER_FRAME_INTERVAL: Duration = Duration::from_secs(5);
+/// The time between two updates of the game logic while the game is in low power mode.
+pub const LOW_POWER_LOGIC_TICK: Duration = Duration::from_secs(5);
+
+/// ## GameLoop
+/// Decides when the game logic is updated and when a new frame is drawn. The logic is updated at a fixed
+/// tick rate, no matter how often frames are drawn or how often the player presses a key. Frames are only
+/// drawn when something changed on the screen, or while something is animating, and never faster than the frame rate.
+#[derive(Debug, Clone)]
+pub struct GameLoop {
+    frame_interval: Duration,
+    next_tick: Instant,
+    next_frame: Instant,
+    dirty: bool,
+    low_power: bool,
+}
+
+impl GameLoop {
+    /// Creates a new `GameLoop` that draws the first frame right away.
+    /// <br>
+    /// ## parameters:
+    /// * `fps` - The most frames that are drawn per second.
+    /// * `now` - The current time.
+    pub fn new(fps: u32, now: Instant) -> Self {
+        Self {
+            frame_interval: Duration::from_secs(1) / fps.clamp(1, MAX_FPS),
+            next_tick: now + LOGIC_TICK,
+            next_frame: now,
+            dirty: true,
+            low_power: false,
+        }
+    }
+
+    /// Returns how many logic ticks passed since the last call.
+    /// <br>
+    /// ## parameters:
+    /// * `now` - The current time.
+    pub fn take_ticks(&mut self, now: Instant) -> u32 {
+        let tick = self.logic_tick();
+        let mut ticks = 0;
+        while self.next_tick <= now {
+            self.next_tick += tick;
+            ticks += 1;
+        }
+
+        if ticks > MAX_CATCH_UP_TICKS {
+            // Start counting from now again, instead of catching up on every tick that was missed.
+            self.next_tick = now + tick;
+            ticks = MAX_CATCH_UP_TICKS;
+        }
+        ticks
+    }
+
+    /// Marks the screen as changed, so the next frame is drawn as soon as the frame rate allows.
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Switches the low power mode on or off. In low power mode the logic is updated and a frame is drawn only
+    /// every few seconds, for example while the terminal isn't focused. When it is switched off the logic
+    /// is updated and the next frame is drawn right away.
+    /// <br>
+    /// ## parameters:
+    /// * `low_power` - If the game should use the low power mode.
+    /// * `now` - The current time.
+    pub fn set_low_power(&mut self, low_power: bool, now: Instant) {
+        if self.low_power == low_power {
+            return;
+        }
+
+        self.low_power = low_power;
+        if low_power {
+            self.next_frame = now + LOW_POWER_FRAME_INTERVAL;
+        } else {
+            self.next_tick = now;
+            self.next_frame = now;
+            self.dirty = true;
+        }
+    }
+
+    pub fn is_low_power(&self) -> bool {
+        self.low_power
+    }
+
+    /// Returns the time between two updates of the game logic, which is longer in low power mode.
+    fn logic_tick(&self) -> Duration {
+        if self.low_power {
+            LOW_POWER_LOGIC_TICK
+        } else {
+            LOGIC_TICK
+        }
+    }
+
+    /// Returns true when a frame should be drawn now.
+    /// <br>
+    /// ## parameters:
+    /// * `now` - The current time.
+    /// * `animating` - If something on the screen moves on its own, which needs a new frame every time the frame rate allows.
+    pub fn should_draw(&mut self, now: Instant, animating: bool) -> bool {
+        if now < self.next_frame {
+            return false;
+        }
+
+        if self.low_power {
+            // Changes are only shown every few seconds, animations aren't shown at all.
+            self.dirty = false;
+            self.next_frame = now + LOW_POWER_FRAME_INTERVAL;
+            return true;
+        }
+
+        if !(self.dirty || animating) {
+            return false;
+        }
+
+        self.dirty = false;
+        self.next_frame = now + self.frame_interval;
+        true
+    }
+
+    /// Returns how long the game can wait for input, before it has to update the logic or draw a frame.
+    /// <br>
+    /// ## parameters:
+    /// * `now` - The current time.
+    /// * `animating` - If something on the screen moves on its own.
+    pub fn timeout(&self, now: Instant, animating: bool) -> Duration {
+        let next = if self.low_power || self.dirty || animating {
+            self.next_tick.min(self.next_frame)
+        } else {
+            self.next_tick
+        };
+        next.saturating_duration_since(now)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+    use crate::game_loop::{GameLoop, LOGIC_TICK, LOW_POWER_FRAME_INTERVAL, LOW_POWER_LOGIC_TICK, MAX_CATCH_UP_TICKS};
+
+    #[test]
+    fn ticks_and_frames_are_independent() {
+        let start = Instant::now();
+        let mut game_loop = GameLoop::new(10, start);
+
+        // The first frame is drawn right away, after that only when something changed.
+        assert!(game_loop.should_draw(start, false));
+        assert!(!game_loop.should_draw(start + Duration::from_millis(200), false));
+        assert_eq!(game_loop.timeout(start, false), LOGIC_TICK);
+
+        // Frames are drawn at most 10 times per second, even while animating.
+        assert!(!game_loop.should_draw(start + Duration::from_millis(50), true));
+        assert!(game_loop.should_draw(start + Duration::from_millis(100), true));
+
+        game_loop.mark_dirty();
+        assert!(game_loop.should_draw(start + Duration::from_millis(250), false));
+
+        assert_eq!(game_loop.take_ticks(start + Duration::from_millis(400)), 0);
+        assert_eq!(game_loop.take_ticks(start + LOGIC_TICK * 2), 2);
+        assert_eq!(game_loop.take_ticks(start + LOGIC_TICK * 100), MAX_CATCH_UP_TICKS);
+        assert_eq!(game_loop.take_ticks(start + LOGIC_TICK * 100), 0);
+    }
+
+    #[test]
+    fn low_power_draws_slowly() {
+        let start = Instant::now();
+        let mut game_loop = GameLoop::new(30, start);
+        assert!(game_loop.should_draw(start, false));
+
+        game_loop.set_low_power(true, start);
+        game_loop.mark_dirty();
+        assert!(!game_loop.should_draw(start + Duration::from_secs(1), true));
+        assert_eq!(game_loop.timeout(start + Duration::from_millis(100), true), Duration::from_millis(400));
+        assert!(game_loop.should_draw(start + LOW_POWER_FRAME_INTERVAL, false));
+
+        // Getting the focus back shows the current state right away.
+        let focused = start + LOW_POWER_FRAME_INTERVAL + Duration::from_secs(1);
+        game_loop.set_low_power(false, focused);
+        assert!(game_loop.should_draw(focused, false));
+    }
+
+    #[test]
+    fn low_power_ticks_slowly() {
+        let start = Instant::now();
+        let mut game_loop = GameLoop::new(30, start);
+        game_loop.set_low_power(true, start);
+
+        // The tick that was already planned still happens, after that the logic is updated every few seconds.
+        assert_eq!(game_loop.take_ticks(start + LOGIC_TICK), 1);
+        assert_eq!(game_loop.take_ticks(start + LOGIC_TICK * 4), 0);
+        assert_eq!(game_loop.take_ticks(start + LOGIC_TICK + LOW_POWER_LOGIC_TICK), 1);
+
+        // Getting the focus back updates the logic right away, and at the normal rate again.
+        let focused = start + LOW_POWER_LOGIC_TICK * 2;
+        game_loop.set_low_power(false, focused);
+        assert_eq!(game_loop.take_ticks(focused), 1);
+        assert_eq!(game_loop.take_ticks(focused + LOGIC_TICK), 1);
+    }
+}
@@ -1,26 +1,154 @@
-use crate::friend::Friend;
-use chrono::Utc;
+use crate::friend::{Friend, GrowthStage};
+use crate::shapes::scenery::Scenery;
+use crate::environment::Environment;
+use crate::weather::Weather;
+use crate::item::{Item, PlacedItem};
+use crate::inventory::Inventory;
+use crate::shop::ShopItem;
+use crate::food::Food;
+use crate::achievements::{Achievement, Achievements};
+use crate::history::StatHistory;
+use crate::tutorial::Tutorial;
+use ratatui::layout::Rect;
+use crate::clock::{self, Clock};
 use serde::{Deserialize, Serialize};
 use std::fs::OpenOptions;
 use std::io::{Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::SystemTime;
 
-const SAVE_FILE_PATH: &str = "./save-file.txt";
+pub const SAVE_FILE_PATH: &str = "./save-file.txt";
+/// The save file used while the clock is scaled, so the pet in the real save file doesn't age along with it.
+pub const SCALED_SAVE_FILE_PATH: &str = "./save-file-scaled.txt";
+const DAY_MILLIS: i64 = 1000 * 60 * 60 * 24;
+/// The amount of coins earned for playing a game with the pet.
+const PLAY_REWARD: u32 = 2;
+/// The amount of coins earned for every full day the pet survives.
+const DAILY_REWARD: u32 = 10;
+/// The amount of coins earned for unlocking an achievement.
+const ACHIEVEMENT_REWARD: u32 = 25;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
     friend: Friend,
     last_update_time: i64,
+    #[serde(default = "default_lights_on")]
+    lights_on: bool,
+    #[serde(default)]
+    chosen_scenery: Option<Scenery>,
+    #[serde(default)]
+    unlocked_sceneries: Vec<Scenery>,
+    /// Save files from before the weather was added have no seed, their weather comes from when the friend was created.
+    #[serde(default)]
+    weather_seed: Option<u64>,
+    #[serde(default = "default_items")]
+    items: Vec<PlacedItem>,
+    #[serde(default = "Inventory::starter")]
+    inventory: Inventory,
+    #[serde(default)]
+    days_rewarded: i64,
+    #[serde(default)]
+    achievements: Achievements,
+    #[serde(default)]
+    history: StatHistory,
+    #[serde(default)]
+    tutorial: Tutorial,
+    /// Achievements that were unlocked but haven't been shown to the player yet.
+    #[serde(skip)]
+    new_achievements: Vec<Achievement>,
+    /// The clock that tells the game state what time it is.
+    #[serde(skip, default = "clock::global")]
+    clock: Arc<dyn Clock>,
+    /// Goes up every time the game state changes, so other processes can cheaply check if they have the latest state.
+    #[serde(skip)]
+    revision: u64,
+}
+
+/// The parts of the game state that change on their own as time passes,
+/// used to find out if an update changed anything the player can see.
+#[derive(PartialEq)]
+struct Snapshot {
+    stats: [u32; 4],
+    growth_stage: GrowthStage,
+    asleep: bool,
+    alive: bool,
+    coins: u32,
+    achievements: usize,
+    tutorial: Tutorial,
+    latest_sample: Option<i64>,
 }
 
 impl GameState {
     pub fn new(friend: Friend) -> Self {
+        Self::with_clock(friend, clock::global())
+    }
+
+    /// Creates a new game state that uses the given clock instead of the clock of the whole game,
+    /// for example to simulate a couple of days in a test.
+    /// <br>
+    /// ## parameters:
+    /// * `friend` - The friend to take care of.
+    /// * `clock` - The clock that tells the game state what time it is.
+    pub fn with_clock(friend: Friend, clock: Arc<dyn Clock>) -> Self {
         Self {
             friend,
-            last_update_time: Utc::now().timestamp_millis(),
+            last_update_time: clock.now_millis(),
+            lights_on: true,
+            chosen_scenery: None,
+            unlocked_sceneries: Vec::new(),
+            weather_seed: Some(new_weather_seed()),
+            items: default_items(),
+            inventory: Inventory::starter(),
+            days_rewarded: 0,
+            achievements: Achievements::default(),
+            history: StatHistory::default(),
+            // A brand new game means a new player, who gets the tutorial.
+            tutorial: Tutorial::Hatch,
+            new_achievements: Vec::new(),
+            clock,
+            revision: 0,
         }
     }
 
-    pub fn store_to_file(&mut self) -> std::io::Result<()> {
+    /// Replaces the current friend with a new one, for example after the old one has died.
+    /// Progress that isn't bound to a single friend, like unlocked sceneries and achievements, is kept.
+    /// <br>
+    /// ## parameters:
+    /// * `friend` - The new friend to take care of.
+    pub fn replace_friend(&mut self, friend: Friend) {
+        let unlocked_sceneries = std::mem::take(&mut self.unlocked_sceneries);
+        let chosen_scenery = self.chosen_scenery;
+        // The seed of an old save file is kept, so its weather doesn't change with the new friend.
+        let weather_seed = Some(self.weather_seed());
+        let items = std::mem::take(&mut self.items);
+        let inventory = self.inventory.clone();
+        let achievements = std::mem::take(&mut self.achievements);
+        let new_achievements = std::mem::take(&mut self.new_achievements);
+        let tutorial = self.tutorial;
+        let revision = self.revision;
+
+        *self = Self::with_clock(friend, Arc::clone(&self.clock));
+        self.revision = revision;
+        // A player who didn't finish the tutorial starts over with the new egg.
+        self.tutorial = match tutorial {
+            Tutorial::Done | Tutorial::Finished => Tutorial::Finished,
+            _ => Tutorial::Hatch,
+        };
+        self.achievements = achievements;
+        self.new_achievements = new_achievements;
+        self.unlocked_sceneries = unlocked_sceneries;
+        self.weather_seed = weather_seed;
+        self.items = items;
+        self.inventory = inventory;
+        self.chosen_scenery = chosen_scenery.filter(|scenery| self.available_sceneries().contains(scenery));
+    }
+
+    /// Writes the game state to the given save file.
+    /// <br>
+    /// ## parameters:
+    /// * `path` - The path of the save file.
+    pub fn store_to_file(&mut self, path: &Path) -> std::io::Result<()> {
         self.update(); // Update so we store the latest changes.
         let serialized = serde_json::to_string(&self)?;
 
@@ -28,7 +156,7 @@ impl GameState {
             .create(true)
             .write(true)
             .truncate(true)
-            .open(SAVE_FILE_PATH)?;
+            .open(path)?;
 
         save_file.set_len(0)?;
         save_file.write_all(serialized.as_bytes())?;
@@ -37,8 +165,12 @@ impl GameState {
         Ok(())
     }
 
-    pub fn read_from_file() -> std::io::Result<Self> {
-        let mut save_file = OpenOptions::new().read(true).open(SAVE_FILE_PATH)?;
+    /// Reads the game state from the given save file.
+    /// <br>
+    /// ## parameters:
+    /// * `path` - The path of the save file.
+    pub fn read_from_file(path: &Path) -> std::io::Result<Self> {
+        let mut save_file = OpenOptions::new().read(true).open(path)?;
 
         let mut save_buffer = String::new();
         save_file.read_to_string(&mut save_buffer)?;
@@ -48,17 +180,447 @@ impl GameState {
         Ok(state)
     }
 
+    /// Returns the last time the save file was modified, which is used to notice when another process changed it.
+    /// <br>
+    /// ## parameters:
+    /// * `path` - The path of the save file.
+    pub fn save_file_modified(path: &Path) -> std::io::Result<SystemTime> {
+        std::fs::metadata(path)?.modified()
+    }
+
+    /// Returns the current utc time in millis, as told by the clock of the game state.
+    pub fn now(&self) -> i64 {
+        self.clock.now_millis()
+    }
+
+    /// Returns the revision of the game state, which changes every time the game state changes.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Marks the game state as changed, after the player did something.
+    pub fn mark_changed(&mut self) {
+        self.revision += 1;
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            stats: [self.friend.food(), self.friend.joy(), self.friend.energy(), self.friend.health()].map(|stat| stat.value()),
+            growth_stage: self.friend.growth_stage(),
+            asleep: self.friend.is_asleep(),
+            alive: *self.friend.alive(),
+            coins: self.inventory.coins(),
+            achievements: self.achievements.unlocked_count(),
+            tutorial: self.tutorial,
+            latest_sample: self.history.latest().map(|sample| sample.time),
+        }
+    }
+
     pub fn update(&mut self) {
-        let now =Utc::now().timestamp_millis();
+        let now = self.now();
         self.last_update_time = now;
-        self.friend.update_state(now);
+        let before = self.snapshot();
+
+        let previous_growth_stage = self.friend.growth_stage();
+        self.friend.update_state(now, &self.environment());
+        self.update_unlocked_sceneries();
+        self.update_earnings(now, previous_growth_stage);
+        self.update_achievements(now);
+        self.history.sample(&self.friend, now);
+        self.tutorial.progress(&self.friend, self.history.totals());
+
+        if self.snapshot() != before {
+            self.mark_changed();
+        }
+    }
+
+    /// Checks for newly unlocked achievements, and rewards the player for each of them.
+    /// <br>
+    /// ## parameters:
+    /// * `now` - The current utc time in millis.
+    fn update_achievements(&mut self, now: i64) {
+        let unlocked = self.achievements.check(&self.friend, now);
+        for _ in &unlocked {
+            self.inventory.earn(ACHIEVEMENT_REWARD);
+        }
+        self.new_achievements.extend(unlocked);
+    }
+
+    pub fn tutorial(&self) -> Tutorial {
+        self.tutorial
+    }
+
+    /// Closes the tutorial, the player can do this at any step.
+    pub fn finish_tutorial(&mut self) {
+        self.tutorial = Tutorial::Finished;
+    }
+
+    /// Returns the history of the current friend's stats.
+    pub fn history(&self) -> &StatHistory {
+        &self.history
+    }
+
+    pub fn achievements(&self) -> &Achievements {
+        &self.achievements
+    }
+
+    /// Returns the achievements that were unlocked since this method was last called,
+    /// so they can be announced to the player.
+    pub fn take_new_achievements(&mut self) -> Vec<Achievement> {
+        std::mem::take(&mut self.new_achievements)
+    }
+
+    /// Pays out the coins the player earned by reaching a new growth stage,
+    /// or by keeping their pet alive for another day.
+    /// <br>
+    /// ## parameters:
+    /// * `now` - The current utc time in millis.
+    /// * `previous_growth_stage` - The growth stage of the friend before it was last updated.
+    fn update_earnings(&mut self, now: i64, previous_growth_stage: GrowthStage) {
+        if !*self.friend.alive() {
+            return;
+        }
+
+        let growth_stage = self.friend.growth_stage();
+        if growth_stage != previous_growth_stage {
+            self.inventory.earn(match growth_stage {
+                GrowthStage::Egg => 0,
+                GrowthStage::Baby => 10,
+                GrowthStage::Kid => 20,
+                GrowthStage::Adult => 50,
+            });
+        }
+
+        let days_alive = (now - self.friend.time_created()) / DAY_MILLIS;
+        while self.days_rewarded < days_alive {
+            self.inventory.earn(DAILY_REWARD);
+            self.days_rewarded += 1;
+        }
+    }
+
+    /// Returns true when the friend is able to eat, play or take medicine.
+    fn can_take_care(&self) -> bool {
+        !self.friend.is_asleep() && self.friend.growth_stage() != GrowthStage::Egg
+    }
+
+    /// Feeds the friend the best food from the inventory that it eats.
+    /// <br>
+    /// ## returns:
+    /// * `Some(Food)` - The food the friend ate.
+    /// * `None` - When the friend can't eat right now, isn't hungry, or there is no food left that it eats.
+    pub fn feed(&mut self) -> Option<Food> {
+        if !self.can_take_care() || self.friend.food().is_max() {
+            return None;
+        }
+
+        let food = self.inventory.take_best_food(&self.friend.shape().diet())?;
+        self.friend.eat(food);
+        self.history.record_meal();
+        Some(food)
+    }
+
+    /// Plays a game with the friend, which earns the player some coins when the friend enjoyed it.
+    /// <br>
+    /// ## returns:
+    /// True when the friend enjoyed playing, false when it couldn't play or already had all the fun it could have.
+    pub fn play(&mut self) -> bool {
+        if !self.can_take_care() {
+            return false;
+        }
+
+        let enjoyed = !self.friend.joy().is_max();
+        self.friend.play();
+        self.history.record_play_session();
+        if enjoyed {
+            self.inventory.earn(PLAY_REWARD);
+        }
+        enjoyed
+    }
+
+    /// Pets the friend, which it only enjoys when it is awake and hasn't just been petted.
+    /// <br>
+    /// ## returns:
+    /// True when the friend enjoyed being petted.
+    pub fn pet(&mut self) -> bool {
+        self.can_take_care() && self.friend.pet(self.now())
+    }
+
+    /// Puts the friend to bed, or wakes it up when it is asleep.
+    pub fn toggle_sleep(&mut self) {
+        let now = self.now();
+        self.friend.toggle_sleep(now);
+    }
+
+    /// Gives the friend a dose of medicine from the inventory.
+    /// <br>
+    /// ## returns:
+    /// True when the friend took the medicine, false when it isn't needed or there is no medicine left.
+    pub fn give_medicine(&mut self) -> bool {
+        if !self.can_take_care() || self.friend.health().is_max() || !self.inventory.take_medicine() {
+            return false;
+        }
+
+        self.friend.take_medicine();
+        self.history.record_medicine_dose();
+        true
+    }
+
+    /// Buys an item from the shop, when the player has enough coins for it.
+    /// <br>
+    /// ## parameters:
+    /// * `shop_item` - The item that should be bought.
+    ///
+    /// ## returns:
+    /// True when the item was bought, false when the player can't afford it or already owns it.
+    pub fn buy(&mut self, shop_item: ShopItem) -> bool {
+        if !self.can_buy(shop_item) || !self.inventory.spend(shop_item.price()) {
+            return false;
+        }
+
+        match shop_item {
+            ShopItem::Food(food) => self.inventory.add_food(food),
+            ShopItem::Medicine => self.inventory.add_medicine(),
+            ShopItem::Item(item) => self.items.push(PlacedItem::new(item)),
+            ShopItem::Paint(color) => self.friend.repaint(color),
+        }
+        true
+    }
+
+    /// Returns false for items that can only be owned once and are already owned.
+    pub fn can_buy(&self, shop_item: ShopItem) -> bool {
+        match shop_item {
+            ShopItem::Item(item) => !self.has_item(item),
+            ShopItem::Paint(color) => self.friend.shape().get_color() != color,
+            _ => true,
+        }
+    }
+
+    pub fn inventory(&self) -> &Inventory {
+        &self.inventory
+    }
+
+    /// Unlocks the habitat of the current friend once it has grown up, so it can
+    /// also be chosen as scenery for future friends.
+    fn update_unlocked_sceneries(&mut self) {
+        let habitat = self.friend.shape().habitat();
+        if self.friend.growth_stage() == GrowthStage::Adult && !self.unlocked_sceneries.contains(&habitat) {
+            self.unlocked_sceneries.push(habitat);
+        }
     }
     
     pub fn friend(&self) -> &Friend {
         &self.friend
     }
 
-    pub fn friend_mut(&mut self) -> &mut Friend {
-        &mut self.friend
+    pub fn lights_on(&self) -> bool {
+        self.lights_on
+    }
+
+    /// Switches the lights in the pet's room on or off.
+    pub fn toggle_lights(&mut self) {
+        self.lights_on = !self.lights_on;
+    }
+
+    /// Returns the surroundings of the friend, which influence how its stats change over time.
+    pub fn environment(&self) -> Environment {
+        Environment {
+            lights_on: self.lights_on,
+            weather_seed: self.weather_seed(),
+            has_bed: self.has_item(Item::Bed),
+        }
+    }
+
+    /// Returns the seed of the weather of this save file, which is derived from when the friend was created
+    /// for save files without a seed, so their weather stays the same every time they are loaded.
+    fn weather_seed(&self) -> u64 {
+        self.weather_seed.unwrap_or(self.friend.time_created() as u64)
+    }
+
+    pub fn items(&self) -> &[PlacedItem] {
+        &self.items
+    }
+
+    pub fn has_item(&self, item: Item) -> bool {
+        self.items.iter().any(|placed| placed.item == item)
+    }
+
+    /// Returns the areas taken up by the placed items in the given playground,
+    /// these act as obstacles the creature has to walk around.
+    /// <br>
+    /// ## parameters:
+    /// * `area` - The playground the items are placed in.
+    pub fn item_bounds(&self, area: Rect) -> Vec<Rect> {
+        self.items.iter()
+            .map(|placed| placed.bounds(area))
+            .collect()
+    }
+
+    /// Returns the weather in the playground at the given moment.
+    /// <br>
+    /// ## parameters:
+    /// * `now` - The utc time in millis to get the weather for.
+    pub fn weather(&self, now: i64) -> Weather {
+        self.environment().weather_at(now)
+    }
+
+    /// Returns the scenery that is drawn behind the friend. Unless the player chose another one,
+    /// this is the natural habitat of the friend.
+    pub fn scenery(&self) -> Scenery {
+        self.chosen_scenery.unwrap_or_else(|| self.friend.shape().habitat())
+    }
+
+    /// Returns all sceneries the player can currently choose from. The plain scenery and the
+    /// habitat of the current friend are always available.
+    pub fn available_sceneries(&self) -> Vec<Scenery> {
+        let mut sceneries = vec![Scenery::Plain, self.friend.shape().habitat()];
+        for scenery in &self.unlocked_sceneries {
+            if !sceneries.contains(scenery) {
+                sceneries.push(*scenery);
+            }
+        }
+        sceneries.dedup();
+        sceneries
+    }
+
+    /// Switches to the next scenery the player is able to choose from.
+    pub fn cycle_scenery(&mut self) {
+        let sceneries = self.available_sceneries();
+        let current = sceneries.iter()
+            .position(|scenery| *scenery == self.scenery())
+            .unwrap_or(0);
+
+        self.chosen_scenery = Some(sceneries[(current + 1) % sceneries.len()]);
+    }
+}
+
+/// Save files from before the lights were added should start with the lights on.
+fn default_lights_on() -> bool {
+    true
+}
+
+/// The toys and furniture every player starts out with, the rest can be bought in the shop.
+fn default_items() -> Vec<PlacedItem> {
+    vec![PlacedItem::new(Item::Ball)]
+}
+
+/// Every save file gets its own weather, generated from a random seed.
+fn new_weather_seed() -> u64 {
+    rand::random()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::friend::Friend;
+    use crate::game_state::GameState;
+    use crate::shapes::creatures::CreatureShapes;
+    use crate::shapes::scenery::Scenery;
+    use crate::utils::ColorWrapper;
+    use crate::food::{Food, FOODS};
+    use crate::item::Item;
+    use crate::shop::ShopItem;
+    use std::sync::Arc;
+    use chrono::{Local, TimeZone};
+    use crate::clock::FakeClock;
+    use crate::friend::GrowthStage;
+    use crate::tutorial::Tutorial;
+
+    #[test]
+    fn pet_grows_up_over_a_few_days() {
+        let born = Local.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap().timestamp_millis();
+        let clock = Arc::new(FakeClock::new(born));
+        let friend = Friend::born_at("duck", CreatureShapes::Duck(ColorWrapper::Red), born);
+        let mut game_state = GameState::with_clock(friend, clock.clone());
+
+        // Take care of the pet every half an hour, for three days.
+        for _ in 0..3 * 24 * 2 {
+            clock.advance(30 * 60 * 1000);
+            game_state.update();
+
+            let friend = game_state.friend();
+            let (food, joy, energy, health) = (friend.food().value(), friend.joy().value(), friend.energy().value(), friend.health().value());
+            if food < 50 && game_state.feed().is_none() && game_state.buy(ShopItem::Food(Food::Soup)) {
+                game_state.feed();
+            }
+            if joy < 50 {
+                game_state.play();
+            }
+            if health < 50 {
+                game_state.give_medicine();
+            }
+            if energy < 20 && !game_state.friend().is_asleep() {
+                game_state.toggle_sleep();
+            }
+        }
+
+        assert!(*game_state.friend().alive());
+        assert_eq!(game_state.friend().growth_stage(), GrowthStage::Adult);
+        assert_eq!(game_state.days_rewarded, 3);
+    }
+
+    #[test]
+    fn unlocked_sceneries_survive_new_friend() {
+        let mut game_state = GameState::new(Friend::new("duck", CreatureShapes::Duck(ColorWrapper::Red)));
+        game_state.unlocked_sceneries.push(Scenery::Pond);
+
+        game_state.replace_friend(Friend::new("snail", CreatureShapes::Snail(ColorWrapper::Red)));
+        assert_eq!(game_state.tutorial(), Tutorial::Hatch);
+        game_state.finish_tutorial();
+        game_state.replace_friend(Friend::new("snail", CreatureShapes::Snail(ColorWrapper::Red)));
+        assert_eq!(game_state.tutorial(), Tutorial::Finished);
+        assert_eq!(game_state.scenery(), Scenery::Grass);
+        assert_eq!(game_state.available_sceneries(), vec![Scenery::Plain, Scenery::Grass, Scenery::Pond]);
+
+        game_state.cycle_scenery();
+        assert_eq!(game_state.scenery(), Scenery::Pond);
+        game_state.cycle_scenery();
+        assert_eq!(game_state.scenery(), Scenery::Plain);
+    }
+
+    #[test]
+    fn feeding_uses_food_from_the_inventory() {
+        let mut game_state = GameState::new(Friend::new("duck", CreatureShapes::Duck(ColorWrapper::Red)));
+        game_state.friend.update_state(game_state.friend.time_created() + 6 * 60 * 1000, &game_state.environment());
+        while game_state.inventory.take_best_food(&FOODS).is_some() {}
+
+        assert_eq!(game_state.feed(), None);
+        assert!(game_state.buy(ShopItem::Food(Food::Soup)));
+        assert_eq!(game_state.feed(), Some(Food::Soup));
+        assert_eq!(game_state.inventory().food_count(), 0);
+    }
+
+    #[test]
+    fn shop_purchases_cost_coins() {
+        let mut game_state = GameState::new(Friend::new("duck", CreatureShapes::Duck(ColorWrapper::Red)));
+        let starting_coins = game_state.inventory().coins();
+        let starting_food = game_state.inventory().food_count();
+
+        assert!(game_state.buy(ShopItem::Food(Food::Burger)));
+        assert_eq!(game_state.inventory().coins(), starting_coins - ShopItem::Food(Food::Burger).price());
+        assert_eq!(game_state.inventory().food_count(), starting_food + 1);
+
+        // The ball is already owned, and the bed is too expensive for a starting player.
+        assert!(!game_state.buy(ShopItem::Item(Item::Ball)));
+        assert!(!game_state.buy(ShopItem::Item(Item::Bed)));
+        assert_eq!(game_state.inventory().coins(), starting_coins - ShopItem::Food(Food::Burger).price());
+    }
+
+    #[test]
+    fn old_saves_keep_their_weather() {
+        let born = Local.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap().timestamp_millis();
+        let friend = Friend::born_at("duck", CreatureShapes::Duck(ColorWrapper::Red), born);
+        let game_state = GameState::with_clock(friend, Arc::new(FakeClock::new(born)));
+
+        // A save file from before the weather was added.
+        let mut save = serde_json::to_value(&game_state).unwrap();
+        save.as_object_mut().unwrap().remove("weather_seed");
+        let load = || serde_json::from_value::<GameState>(save.clone()).unwrap();
+
+        assert_eq!(load().environment().weather_seed, born as u64);
+        assert_eq!(load().environment().weather_seed, load().environment().weather_seed);
+
+        let mut game_state = load();
+        game_state.replace_friend(Friend::born_at("turtle", CreatureShapes::Turtle(ColorWrapper::Red), born + 1000));
+        assert_eq!(game_state.environment().weather_seed, born as u64);
     }
 }
@@ -0,0 +1,116 @@
+use std::collections::VecDeque;
+use serde::{Deserialize, Serialize};
+use crate::friend::Friend;
+
+/// How often the stats of the friend are recorded.
+pub const SAMPLE_INTERVAL_MILLIS: i64 = 1000 * 60 * 15; // 15 minutes
+/// The maximum amount of samples that are kept, which is enough for a full week.
+const MAX_SAMPLES: usize = 7 * 24 * 4;
+
+/// The stats of the friend at a single moment in time.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatSample {
+    pub time: i64,
+    pub food: u32,
+    pub joy: u32,
+    pub energy: u32,
+    pub health: u32,
+}
+
+/// Counts how often the player took care of their friend during its life.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LifetimeTotals {
+    pub meals: u32,
+    pub play_sessions: u32,
+    pub medicine_doses: u32,
+}
+
+/// Keeps a bounded history of the friend's stats, and totals of the care it received.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatHistory {
+    samples: VecDeque<StatSample>,
+    totals: LifetimeTotals,
+}
+
+impl StatHistory {
+    /// Records the current stats of the friend, when enough time has passed since the last sample.
+    /// Once the history is full, the oldest sample is dropped.
+    /// <br>
+    /// ## parameters:
+    /// * `friend` - The friend to record the stats of.
+    /// * `now` - The current utc time in millis.
+    pub fn sample(&mut self, friend: &Friend, now: i64) {
+        if let Some(last) = self.samples.back() {
+            if now - last.time < SAMPLE_INTERVAL_MILLIS {
+                return;
+            }
+        }
+
+        if self.samples.len() >= MAX_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(StatSample {
+            time: now,
+            food: friend.food().value(),
+            joy: friend.joy().value(),
+            energy: friend.energy().value(),
+            health: friend.health().value(),
+        });
+    }
+
+    /// Returns all samples that were taken after the given moment, oldest first.
+    /// <br>
+    /// ## parameters:
+    /// * `since` - The utc time in millis from which samples should be returned.
+    pub fn samples_since(&self, since: i64) -> impl Iterator<Item = &StatSample> {
+        self.samples.iter().filter(move |sample| sample.time >= since)
+    }
+
+    /// Returns the most recent sample, if any sample was taken yet.
+    pub fn latest(&self) -> Option<&StatSample> {
+        self.samples.back()
+    }
+
+    pub fn totals(&self) -> LifetimeTotals {
+        self.totals
+    }
+
+    pub fn record_meal(&mut self) {
+        self.totals.meals += 1;
+    }
+
+    pub fn record_play_session(&mut self) {
+        self.totals.play_sessions += 1;
+    }
+
+    pub fn record_medicine_dose(&mut self) {
+        self.totals.medicine_doses += 1;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::friend::Friend;
+    use crate::history::{StatHistory, MAX_SAMPLES, SAMPLE_INTERVAL_MILLIS};
+    use crate::shapes::creatures::CreatureShapes;
+    use crate::utils::ColorWrapper;
+
+    #[test]
+    fn history_is_bounded() {
+        let friend = Friend::new("duck", CreatureShapes::Duck(ColorWrapper::Red));
+        let mut history = StatHistory::default();
+        let start = friend.time_created();
+
+        // Samples taken too soon after each other are skipped.
+        history.sample(&friend, start);
+        history.sample(&friend, start + SAMPLE_INTERVAL_MILLIS / 2);
+        assert_eq!(history.samples_since(start).count(), 1);
+
+        for sample in 1..MAX_SAMPLES as i64 + 10 {
+            history.sample(&friend, start + sample * SAMPLE_INTERVAL_MILLIS);
+        }
+        assert_eq!(history.samples.len(), MAX_SAMPLES);
+        assert_eq!(history.samples_since(start).next().unwrap().time, start + 10 * SAMPLE_INTERVAL_MILLIS);
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use crate::food::Food;
+
+/// Holds the coins of the player, and the supplies they bought that can be used to take care of their pet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Inventory {
+    coins: u32,
+    food: Vec<Food>,
+    medicine: u32,
+}
+
+impl Inventory {
+    /// The inventory every player starts out with.
+    pub fn starter() -> Self {
+        Self {
+            coins: 20,
+            food: vec![Food::Soup; 5],
+            medicine: 3,
+        }
+    }
+
+    pub fn coins(&self) -> u32 {
+        self.coins
+    }
+
+    pub fn earn(&mut self, coins: u32) {
+        self.coins += coins;
+    }
+
+    /// Spends the given amount of coins. <br>
+    /// Returns false and keeps the coins when there aren't enough coins to spend.
+    pub fn spend(&mut self, coins: u32) -> bool {
+        if self.coins < coins {
+            return false;
+        }
+
+        self.coins -= coins;
+        true
+    }
+
+    pub fn add_food(&mut self, food: Food) {
+        self.food.push(food);
+    }
+
+    /// Takes the most nutritious food out of the inventory, if there is any the friend eats.
+    /// <br>
+    /// ## parameters:
+    /// * `diet` - The food the friend eats.
+    pub fn take_best_food(&mut self, diet: &[Food]) -> Option<Food> {
+        let (index, _) = self.food.iter()
+            .enumerate()
+            .filter(|(_, food)| diet.contains(food))
+            .max_by_key(|(_, food)| food.points())?;
+
+        Some(self.food.remove(index))
+    }
+
+    pub fn food_count(&self) -> usize {
+        self.food.len()
+    }
+
+    pub fn add_medicine(&mut self) {
+        self.medicine += 1;
+    }
+
+    /// Takes a single dose of medicine out of the inventory. <br>
+    /// Returns false when there is no medicine left.
+    pub fn take_medicine(&mut self) -> bool {
+        if self.medicine == 0 {
+            return false;
+        }
+
+        self.medicine -= 1;
+        true
+    }
+
+    pub fn medicine(&self) -> u32 {
+        self.medicine
+    }
+}
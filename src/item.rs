@@ -0,0 +1,87 @@
+use ratatui::layout::Rect;
+use serde::{Deserialize, Serialize};
+use crate::load_embedded_sprite;
+use crate::shapes::{PixelImage, PixelVectorShape};
+use crate::utils::{ColorWrapper, Pixel, sprite_management::load_sprite};
+use crate::utils::location::Location;
+
+/// Toys and furniture that can be placed in the playground.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Item {
+    Ball,
+    Bed,
+    Plant,
+}
+
+impl PixelImage for Item {
+    fn pixels(&self) -> Vec<Pixel> {
+        match self {
+            Item::Ball => load_embedded_sprite!("../assets/items/ball.png", ColorWrapper::Red),
+            Item::Bed => load_embedded_sprite!("../assets/items/bed.png", ColorWrapper::Blue),
+            Item::Plant => load_embedded_sprite!("../assets/items/plant.png", ColorWrapper::Green),
+        }
+    }
+}
+
+/// An item that has been placed in the playground. Its position is stored relative to the size
+/// of the playground, so it stays in the same spot when the terminal has a different size.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlacedItem {
+    pub item: Item,
+    pub x_percent: u8,
+    pub y_percent: u8,
+}
+
+impl PlacedItem {
+    /// Places the item at its default spot, away from the center where the young creatures walk.
+    pub fn new(item: Item) -> Self {
+        let (x_percent, y_percent) = match item {
+            Item::Ball => (30, 9),
+            Item::Bed => (5, 9),
+            Item::Plant => (90, 9),
+        };
+
+        Self {
+            item,
+            x_percent,
+            y_percent,
+        }
+    }
+
+    /// Returns the bottom left corner of the item in the given playground.
+    /// <br>
+    /// ## parameters:
+    /// * `area` - The playground the item is placed in.
+    pub fn location(&self, area: Rect) -> Location {
+        let (width, height) = PixelVectorShape::from_pixel_image(&self.item).get_dimensions();
+        let x = u32::from(area.width) * u32::from(self.x_percent) / 100;
+        let y = u32::from(area.height) * u32::from(self.y_percent) / 100;
+
+        // Make sure the item always fits inside the playground.
+        Location::new(
+            x.min(u32::from(area.width).saturating_sub(width)),
+            y.min(u32::from(area.height).saturating_sub(height)),
+        )
+    }
+
+    /// Returns the area the item takes up in the given playground, used to keep the creature
+    /// from walking through it.
+    /// <br>
+    /// ## parameters:
+    /// * `area` - The playground the item is placed in.
+    pub fn bounds(&self, area: Rect) -> Rect {
+        let location = self.location(area);
+        let (width, height) = PixelVectorShape::from_pixel_image(&self.item).get_dimensions();
+        Rect::new(location.x as u16, location.y as u16, width as u16, height as u16)
+    }
+
+    /// Returns the sprite of the item, moved to its spot in the given playground.
+    /// <br>
+    /// ## parameters:
+    /// * `area` - The playground the item is placed in.
+    pub fn shape(&self, area: Rect) -> PixelVectorShape {
+        let location = self.location(area);
+        PixelVectorShape::from_pixel_image(&self.item)
+            .translate(location.x as i32, location.y as i32)
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::OnceLock;
+use ratatui::crossterm::event::KeyCode;
+use serde::{Deserialize, Serialize};
+
+static KEYMAP: OnceLock<Keymap> = OnceLock::new();
+
+/// Everything the player can do with their keyboard.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyAction {
+    Up,
+    Down,
+    Select,
+    Back,
+    Delete,
+    Switch,
+    Help,
+    Quit,
+    Achievements,
+    Statistics,
+    Eat,
+    Play,
+    Sleep,
+    Medicine,
+    Lights,
+    Scenery,
+    Shop,
+}
+
+impl KeyAction {
+    pub fn description(&self) -> &'static str {
+        match self {
+            KeyAction::Up => "Move up in a list",
+            KeyAction::Down => "Move down in a list",
+            KeyAction::Select => "Perform the selected action",
+            KeyAction::Back => "Go back to your pet, or skip the tutorial",
+            KeyAction::Delete => "Remove the last letter of a name",
+            KeyAction::Switch => "Switch between the last day and week, or help pages",
+            KeyAction::Help => "Show or hide the help",
+            KeyAction::Quit => "Quit the game",
+            KeyAction::Achievements => "Show your achievements",
+            KeyAction::Statistics => "Show the statistics of your pet",
+            KeyAction::Eat => "Feed your pet",
+            KeyAction::Play => "Play with your pet",
+            KeyAction::Sleep => "Put your pet to bed, or wake it up",
+            KeyAction::Medicine => "Give your pet medicine",
+            KeyAction::Lights => "Switch the lights on or off",
+            KeyAction::Scenery => "Change the scenery",
+            KeyAction::Shop => "Visit the shop",
+        }
+    }
+
+    /// Returns the entry in the actions list this key action performs, if there is one.
+    pub fn action_item(&self) -> Option<&'static str> {
+        match self {
+            KeyAction::Eat => Some("Eat"),
+            KeyAction::Play => Some("Play"),
+            KeyAction::Sleep => Some("Sleep"),
+            KeyAction::Medicine => Some("Medicine"),
+            KeyAction::Lights => Some("Lights"),
+            KeyAction::Scenery => Some("Scenery"),
+            KeyAction::Shop => Some("Shop"),
+            _ => None,
+        }
+    }
+}
+
+/// ## Keymap
+/// Holds the keys that are bound to every `KeyAction`. Keys are written the way they are shown to
+/// the player, like `"q"`, `"enter"` or `"up"`. The defaults can be changed in the config file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keymap {
+    bindings: BTreeMap<KeyAction, Vec<String>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = [
+            (KeyAction::Up, vec!["up", "k"]),
+            (KeyAction::Down, vec!["down", "j"]),
+            (KeyAction::Select, vec!["enter"]),
+            (KeyAction::Back, vec!["esc"]),
+            (KeyAction::Delete, vec!["backspace"]),
+            (KeyAction::Switch, vec!["tab"]),
+            (KeyAction::Help, vec!["?"]),
+            (KeyAction::Quit, vec!["q"]),
+            (KeyAction::Achievements, vec!["a"]),
+            (KeyAction::Statistics, vec!["s"]),
+            (KeyAction::Eat, vec!["f", "1"]),
+            (KeyAction::Play, vec!["p", "2"]),
+            (KeyAction::Sleep, vec!["z", "3"]),
+            (KeyAction::Medicine, vec!["m", "4"]),
+            (KeyAction::Lights, vec!["l", "5"]),
+            (KeyAction::Scenery, vec!["6"]),
+            (KeyAction::Shop, vec!["$", "7"]),
+        ];
+
+        Self {
+            bindings: bindings.into_iter()
+                .map(|(action, keys)| (action, keys.into_iter().map(String::from).collect()))
+                .collect(),
+        }
+    }
+}
+
+impl Keymap {
+    /// Creates a keymap from the default bindings, replacing the keys of every action the player changed.
+    /// <br>
+    /// ## parameters:
+    /// * `overrides` - The keys per action from the config file.
+    pub fn with_overrides(overrides: &HashMap<KeyAction, Vec<String>>) -> Self {
+        let mut keymap = Self::default();
+        for (action, keys) in overrides {
+            keymap.bindings.insert(*action, keys.iter().map(|key| key.to_lowercase()).collect());
+        }
+        keymap
+    }
+
+    /// Checks that every key is bound to a single action, otherwise it's unclear what pressing the key should do.
+    /// <br>
+    /// ## returns:
+    /// [std::io::ErrorKind::InvalidInput] when a key is bound to more than one action.
+    pub fn validate(&self) -> std::io::Result<()> {
+        let mut bound: HashMap<&str, KeyAction> = HashMap::new();
+        for (action, keys) in &self.bindings {
+            for key in keys {
+                if let Some(other) = bound.insert(key, *action) {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!("the key '{key}' is bound to both {other:?} and {action:?}"),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the keymap used by the whole game, this can only be done once.
+    pub fn set_global(keymap: Keymap) {
+        let _ = KEYMAP.set(keymap);
+    }
+
+    /// Returns the keymap used by the whole game, which is the default keymap when none was set.
+    pub fn global() -> &'static Keymap {
+        KEYMAP.get_or_init(Keymap::default)
+    }
+
+    /// Returns true when the key is bound to the given action.
+    /// <br>
+    /// ## parameters:
+    /// * `code` - The key that was pressed.
+    /// * `action` - The action to check.
+    pub fn is(&self, code: KeyCode, action: KeyAction) -> bool {
+        let Some(name) = key_name(code) else {
+            return false;
+        };
+
+        self.bindings.get(&action)
+            .is_some_and(|keys| keys.contains(&name))
+    }
+
+    /// Returns the first action, in the order of `KeyAction`, the key is bound to.
+    pub fn action(&self, code: KeyCode) -> Option<KeyAction> {
+        self.bindings.keys()
+            .find(|action| self.is(code, **action))
+            .copied()
+    }
+
+    /// Returns the keys bound to an action as they are shown to the player, for example `"up/k"`.
+    pub fn keys(&self, action: KeyAction) -> String {
+        self.bindings.get(&action)
+            .map(|keys| keys.join("/"))
+            .unwrap_or_default()
+    }
+
+    /// Returns the first key bound to an action, which is the one shown when there is little room.
+    pub fn first_key(&self, action: KeyAction) -> Option<&str> {
+        self.bindings.get(&action)
+            .and_then(|keys| keys.first())
+            .map(String::as_str)
+    }
+
+    /// Returns all actions with the keys bound to them.
+    pub fn bindings(&self) -> impl Iterator<Item = (KeyAction, String)> + '_ {
+        self.bindings.keys().map(|action| (*action, self.keys(*action)))
+    }
+}
+
+/// Returns the name of a key as it is used in the keymap.
+fn key_name(code: KeyCode) -> Option<String> {
+    let name = match code {
+        KeyCode::Char(' ') => String::from("space"),
+        KeyCode::Char(char) => char.to_lowercase().to_string(),
+        KeyCode::F(number) => format!("f{number}"),
+        KeyCode::Enter => String::from("enter"),
+        KeyCode::Esc => String::from("esc"),
+        KeyCode::Tab => String::from("tab"),
+        KeyCode::Backspace => String::from("backspace"),
+        KeyCode::Delete => String::from("delete"),
+        KeyCode::Up => String::from("up"),
+        KeyCode::Down => String::from("down"),
+        KeyCode::Left => String::from("left"),
+        KeyCode::Right => String::from("right"),
+        KeyCode::Home => String::from("home"),
+        KeyCode::End => String::from("end"),
+        KeyCode::PageUp => String::from("pageup"),
+        KeyCode::PageDown => String::from("pagedown"),
+        _ => return None,
+    };
+    Some(name)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use ratatui::crossterm::event::KeyCode;
+    use crate::keymap::{KeyAction, Keymap};
+
+    #[test]
+    fn default_bindings() {
+        let keymap = Keymap::default();
+        assert!(keymap.validate().is_ok());
+
+        assert_eq!(keymap.action(KeyCode::Char('j')), Some(KeyAction::Down));
+        assert_eq!(keymap.action(KeyCode::Up), Some(KeyAction::Up));
+        assert_eq!(keymap.action(KeyCode::Char('1')), Some(KeyAction::Eat));
+        assert_eq!(keymap.action(KeyCode::Char('F')), Some(KeyAction::Eat));
+        assert_eq!(keymap.action(KeyCode::Char('x')), None);
+        assert_eq!(keymap.keys(KeyAction::Up), "up/k");
+        assert_eq!(keymap.first_key(KeyAction::Up), Some("up"));
+    }
+
+    #[test]
+    fn overrides_replace_default_keys() {
+        let overrides = HashMap::from([(KeyAction::Quit, vec![String::from("X"), String::from("esc")])]);
+        let keymap = Keymap::with_overrides(&overrides);
+
+        assert!(keymap.is(KeyCode::Char('x'), KeyAction::Quit));
+        assert!(keymap.is(KeyCode::Esc, KeyAction::Quit));
+        assert!(!keymap.is(KeyCode::Char('q'), KeyAction::Quit));
+        assert!(keymap.is(KeyCode::Enter, KeyAction::Select));
+    }
+
+    #[test]
+    fn conflicting_overrides_are_rejected() {
+        let overrides = HashMap::from([(KeyAction::Quit, vec![String::from("F")])]);
+        let error = Keymap::with_overrides(&overrides).validate().unwrap_err();
+
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+        assert!(error.to_string().contains("'f'"));
+    }
+}
@@ -0,0 +1,32 @@
+use std::time::Duration;
+use ratatui::crossterm::event::{self, poll, Event, KeyEventKind};
+use ratatui::DefaultTerminal;
+use crate::game_state::GameState;
+use crate::keymap::{KeyAction, Keymap};
+use crate::widgets::achievements_widget::achievements_list;
+
+/// This layout lists all achievements and shows which ones the player has unlocked.
+/// It keeps running until the player goes back to their pet.
+/// <br>
+/// ## parameters:
+/// * `terminal` - The `ratatui::DefaultTerminal` to draw the layout onto.
+/// * `game_state` - The current `GameState`, which holds the unlocked achievements.
+pub fn achievements_layout(terminal: &mut DefaultTerminal, game_state: &GameState) -> std::io::Result<()> {
+    loop {
+        terminal.draw(|frame| {
+            frame.render_widget(achievements_list(game_state), frame.area());
+        })?;
+
+        if poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    if let Some(KeyAction::Back | KeyAction::Quit | KeyAction::Achievements) = Keymap::global().action(key.code) {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -3,21 +3,29 @@ use ratatui::crossterm::event;
 use ratatui::crossterm::event::{poll, Event, KeyCode, KeyEventKind};
 use ratatui::DefaultTerminal;
 use ratatui::layout::{Constraint, Layout};
-use crate::friend::Friend;
-use crate::game_state::GameState;
-use crate::shapes::creatures::CreatureShapes;
+use crate::daemon::{Request, Response, Session};
+use crate::keymap::{KeyAction, Keymap};
 use crate::widgets::friend_death_widget::{death_canvas, name_input};
+use crate::widgets::message_widget::message_box;
 
 /// This layout is used whenever the users pet has died. It will display a short death message<br>
 /// and allow the user to create a new pet. This method will do this by modifying the old<br>
-/// game state.
+/// game state. When the new pet can't be created, for example because the game is read-only,
+/// the reason is shown and the layout waits until another instance of the game created a new pet.
 /// <br>
 /// ## paramters:
 /// * `terminal` - The `ratatui::DefaultTerminal` to draw the layout onto.
-/// * `game_state` - The current `GameState` where the pet has died.
-pub fn friend_death_layout(terminal: &mut DefaultTerminal, game_state: &mut GameState) -> std::io::Result<()> {
+/// * `session` - The current `Session` where the pet has died.
+pub fn friend_death_layout(terminal: &mut DefaultTerminal, session: &mut Session) -> std::io::Result<()> {
     let mut name_buffer = String::new();
+    let mut message: Option<String> = None;
     loop {
+        // Another instance of the game might have created the new pet already.
+        session.refresh()?;
+        if *session.game_state().friend().alive() {
+            return Ok(());
+        }
+
         terminal.draw(|frame| {
             let frame_area = frame.area();
 
@@ -29,28 +37,40 @@ pub fn friend_death_layout(terminal: &mut DefaultTerminal, game_state: &mut Game
             
             
             frame.render_widget(death_canvas(), canvas_area);
+            let input_area = match &message {
+                Some(message) => {
+                    let [input_area, message_area] = Layout::vertical([
+                        Constraint::Fill(1),
+                        Constraint::Length(4),
+                    ])
+                        .areas(input_area);
+                    frame.render_widget(message_box(message), message_area);
+                    input_area
+                },
+                None => input_area,
+            };
             frame.render_widget(name_input(&name_buffer), input_area);
         })?;
 
         if poll(Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
                 if key.kind == KeyEventKind::Press {
-                    match key.code {
-                        KeyCode::Char(input) => name_buffer.push(input),
-                        KeyCode::Backspace => {
-                            let _ = name_buffer.remove(name_buffer.len() -1);
-                        },
+                    let keymap = Keymap::global();
+                    if keymap.is(key.code, KeyAction::Select) {
+                        match session.perform(Request::NewFriend { name: name_buffer.clone() })? {
+                            Response::Error { message: error } => message = Some(error),
+                            _ => return Ok(()),
+                        }
+                        continue;
+                    }
 
-                        KeyCode::Enter => break,
-                        _ => (),
+                    if keymap.is(key.code, KeyAction::Delete) {
+                        name_buffer.pop();
+                    } else if let KeyCode::Char(input) = key.code {
+                        name_buffer.push(input);
                     }
                 }
             }
         }
     }
-    
-    // Adjust the GameState
-    *game_state = GameState::new(Friend::new(&name_buffer, CreatureShapes::new_random()));
-    
-    Ok(())
 }
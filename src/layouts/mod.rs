@@ -1,5 +1,11 @@
 mod new_game;
 mod friend_death;
+mod shop;
+mod achievements;
+mod statistics;
 
 pub use new_game::draw_new_game_layout;
-pub use friend_death::friend_death_layout;
\ No newline at end of file
+pub use friend_death::friend_death_layout;
+pub use shop::shop_layout;
+pub use achievements::achievements_layout;
+pub use statistics::statistics_layout;
\ No newline at end of file
@@ -3,6 +3,7 @@ use ratatui::{crossterm::event::{self, Event, KeyCode, KeyEventKind, poll}, layo
 use crate::shapes::creatures::CreatureShapes;
 use crate::friend::Friend;
 use crate::game_state::GameState;
+use crate::keymap::{KeyAction, Keymap};
 use crate::widgets::new_game_widget::{new_game_dialog, new_game_name_input};
 
 /// Draws the widget that allows the user to create a new `GameState`, used when there is no save file to be found.
@@ -35,14 +36,15 @@ pub fn draw_new_game_layout(terminal: &mut DefaultTerminal) -> std::io::Result<G
         if poll(Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
                 if key.kind == KeyEventKind::Press {
-                    match key.code {
-                        KeyCode::Char(input) => new_name_input.push(input),
-                        KeyCode::Backspace => {
-                            let _ = new_name_input.remove(new_name_input.len() -1);
-                        },
+                    let keymap = Keymap::global();
+                    if keymap.is(key.code, KeyAction::Select) {
+                        break;
+                    }
 
-                        KeyCode::Enter => break,
-                        _ => (),
+                    if keymap.is(key.code, KeyAction::Delete) {
+                        new_name_input.pop();
+                    } else if let KeyCode::Char(input) = key.code {
+                        new_name_input.push(input);
                     }
                 }
             }
@@ -0,0 +1,56 @@
+use std::time::Duration;
+use ratatui::crossterm::event::{self, poll, Event, KeyEventKind};
+use ratatui::DefaultTerminal;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::widgets::ListState;
+use crate::daemon::{Request, Response, Session};
+use crate::keymap::{KeyAction, Keymap};
+use crate::shop::SHOP_ITEMS;
+use crate::widgets::shop_widget::{shop_list, wallet_summary};
+
+/// This layout shows the shop, where the player can spend their coins on food, medicine, toys and cosmetics.
+/// It keeps running until the player leaves the shop.
+/// <br>
+/// ## parameters:
+/// * `terminal` - The `ratatui::DefaultTerminal` to draw the layout onto.
+/// * `session` - The current `Session`, which is updated with everything the player buys.
+pub fn shop_layout(terminal: &mut DefaultTerminal, session: &mut Session) -> std::io::Result<()> {
+    let mut list_state = ListState::default().with_selected(Some(0));
+    let mut message: Option<String> = None;
+
+    loop {
+        terminal.draw(|frame| {
+            let [list_area, wallet_area] = Layout::vertical([
+                Constraint::Min(SHOP_ITEMS.len() as u16 + 2),
+                Constraint::Length(6),
+            ])
+                .areas(frame.area());
+
+            frame.render_stateful_widget(shop_list(session.game_state()), list_area, &mut list_state);
+            frame.render_widget(wallet_summary(session.game_state(), message.as_deref()), wallet_area);
+        })?;
+
+        if poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    match Keymap::global().action(key.code) {
+                        Some(KeyAction::Up) => list_state.select_previous(),
+                        Some(KeyAction::Down) => list_state.select_next(),
+                        Some(KeyAction::Select) => {
+                            if let Some(selected) = list_state.selected() {
+                                message = match session.perform(Request::Buy { item: SHOP_ITEMS[selected] })? {
+                                    Response::Error { message } => Some(message),
+                                    _ => None,
+                                };
+                            }
+                        },
+                        Some(KeyAction::Back | KeyAction::Quit | KeyAction::Shop) => break,
+                        _ => (),
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use std::time::Duration;
+use ratatui::crossterm::event::{self, poll, Event, KeyEventKind};
+use ratatui::DefaultTerminal;
+use ratatui::layout::{Constraint, Layout};
+use crate::game_state::GameState;
+use crate::keymap::{KeyAction, Keymap};
+use crate::widgets::statistics_widget::{HistoryRange, StatisticsWidgetGenerator};
+
+/// This layout shows charts of the friend's stats over time, and totals of the care it received.
+/// It keeps running until the player goes back to their pet.
+/// <br>
+/// ## parameters:
+/// * `terminal` - The `ratatui::DefaultTerminal` to draw the layout onto.
+/// * `game_state` - The current `GameState`, which holds the history of the friend's stats.
+pub fn statistics_layout(terminal: &mut DefaultTerminal, game_state: &GameState) -> std::io::Result<()> {
+    let mut range = HistoryRange::Day;
+
+    loop {
+        terminal.draw(|frame| {
+            let [chart_area, totals_area] = Layout::vertical([
+                Constraint::Min(10),
+                Constraint::Length(5),
+            ])
+                .areas(frame.area());
+
+            let now = game_state.now();
+            let statistics = StatisticsWidgetGenerator::new(game_state.history(), range, now);
+            frame.render_widget(statistics.get_chart(), chart_area);
+            frame.render_widget(statistics.get_totals(), totals_area);
+        })?;
+
+        if poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    match Keymap::global().action(key.code) {
+                        Some(KeyAction::Switch) => range.toggle(),
+                        Some(KeyAction::Back | KeyAction::Quit | KeyAction::Statistics) => break,
+                        _ => (),
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,12 @@
 mod food;
+mod item;
+mod inventory;
+mod shop;
+mod achievements;
+mod history;
+mod day_cycle;
+mod environment;
+mod weather;
 mod friend;
 mod game_state;
 mod shapes;
@@ -8,17 +16,113 @@ mod layouts;
 mod animations;
 mod movements;
 mod app;
+mod config;
+mod cli;
+mod notifier;
+mod daemon;
+mod save_lock;
+mod keymap;
+mod tutorial;
+mod theme;
+mod renderer;
+mod species;
+mod game_loop;
+mod clock;
+mod benchmark;
 
 use crate::app::App;
+use crate::cli::Command;
+use crate::clock::ScaledClock;
+use crate::config::Config;
+use crate::daemon::{Request, Response, Session};
+use crate::keymap::Keymap;
+use crate::renderer::Renderer;
+use crate::theme::Theme;
+use crate::utils::file_logging::log_to_file;
+use ratatui::crossterm::event::{DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture};
+use ratatui::crossterm::execute;
+use std::sync::Arc;
 
 
 fn main() -> std::io::Result<()> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(scale) = cli::take_time_scale(&mut args)? {
+        clock::set_global(Arc::new(ScaledClock::new(scale)));
+    }
+
+    match Command::parse(args.into_iter())? {
+        Command::Game => play(),
+        Command::Notify => notifier::run_notifier(&Config::load()?),
+        Command::Daemon => daemon::run_daemon(),
+        Command::Action(request) => run_action(request),
+        Command::Benchmark => benchmark::run_benchmark(),
+    }
+}
+
+/// Performs a single action from the command line and prints its outcome,
+/// which makes it possible to take care of the pet from scripts or a shell prompt.
+/// <br>
+/// ## parameters:
+/// * `request` - The action to perform.
+fn run_action(request: Request) -> std::io::Result<()> {
+    let mut session = Session::open()?;
+    let name = String::from(session.game_state().friend().name());
+
+    let outcome = match session.perform(request)? {
+        Response::Status(status) => status.to_string(),
+        Response::Fed { food: Some(food) } => format!("{name} ate a {food:?}"),
+        Response::Fed { food: None } => format!("{name} can't eat right now, or you are out of food"),
+        Response::Played { enjoyed: true } => format!("{name} had fun playing"),
+        Response::Played { enjoyed: false } => format!("{name} doesn't want to play right now"),
+        Response::TookMedicine { took_medicine: true } => format!("{name} took its medicine"),
+        Response::TookMedicine { took_medicine: false } => format!("{name} can't take medicine right now"),
+        Response::Error { message } => message,
+        _ if session.game_state().friend().is_asleep() => format!("{name} is asleep"),
+        _ => format!("{name} is awake"),
+    };
+    println!("{outcome}");
+    Ok(())
+}
+
+fn play() -> std::io::Result<()> {
+    // A mistake in the config file shouldn't keep the player from their pet, so it is shown in the game instead.
+    let mut warnings = Vec::new();
+    let config = Config::load().unwrap_or_else(|error| {
+        warnings.push(format!("Using the default settings, config.json is invalid: {error}"));
+        Config::default()
+    });
+    let mut keymap = Keymap::with_overrides(&config.keys);
+    if let Err(error) = keymap.validate() {
+        warnings.push(format!("Using the default keys, {error}"));
+        keymap = Keymap::default();
+    }
+    for warning in &warnings {
+        let _ = log_to_file(warning);
+    }
+
+    Keymap::set_global(keymap);
+    Theme::set_global(Theme::from_config(config.theme));
+    Renderer::set_global(config.renderer);
+
     let mut terminal = ratatui::init();
-    let mut app = App::new(&mut terminal)?;
+    let _guard = TerminalGuard;
+    execute!(std::io::stdout(), EnableMouseCapture, EnableFocusChange)?;
+    let mut app = App::new(&mut terminal, &config)?;
+    if !warnings.is_empty() {
+        app.show_message(warnings.join(" "));
+    }
     
     app.run(&mut terminal)?;
-    app.save_game()?;
-    ratatui::restore();
-    
-    Ok(())
+    app.save_game()
+}
+
+/// Gives the terminal back to the shell when it is dropped, also when the game stopped because of an error.
+/// Mouse capture and focus reporting are turned off again, and the terminal is restored.
+struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(std::io::stdout(), DisableMouseCapture, DisableFocusChange);
+        ratatui::restore();
+    }
 }
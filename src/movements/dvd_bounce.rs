@@ -1,5 +1,4 @@
 use super::movement::Movement;
-use chrono::Utc;
 use rand::Rng;
 use ratatui::layout::Rect;
 use crate::shapes::PixelVectorShape;
@@ -11,21 +10,28 @@ pub struct DvdBounceMovement {
     x_direction_toggle: bool,
     y_direction_toggle: bool,
     area: Rect,
-    last_update: i64,
     friend_shape: PixelVectorShape,
+    obstacles: Vec<Rect>,
 }
 impl DvdBounceMovement {
-    pub fn new(start_location: Location, area: Rect, friend_shape: PixelVectorShape) -> Self {
+    /// Creates a new `DvdBounceMovement` that starts close to the given location.
+    /// <br>
+    /// ## parameters:
+    /// * `start_location` - The location to start bouncing around from.
+    /// * `area` - The area the creature bounces around in.
+    /// * `friend_shape` - The shape of the creature, used to determine when it hits something.
+    /// * `obstacles` - Areas inside the playground the creature bounces off of, like toys.
+    pub fn new(start_location: Location, area: Rect, friend_shape: PixelVectorShape, obstacles: Vec<Rect>) -> Self {
         let mut rng = rand::thread_rng();
         let x_rng = rng.gen_range(-30..=30);
         let y_rng = rng.gen_range(-30..=30);
         
-        let new_x = (start_location.x as i32 + x_rng) as u32;
-        let new_y = (start_location.y as i32 + y_rng) as u32;
+        let new_x = (start_location.x as i32 + x_rng).max(0) as u32;
+        let new_y = (start_location.y as i32 + y_rng).max(0) as u32;
         
         let location = Location {
-            x: new_x - 12, // -12 to account for sprite dimensions (25x25)
-            y: new_y - 12,
+            x: new_x.saturating_sub(12), // -12 to account for sprite dimensions (25x25)
+            y: new_y.saturating_sub(12),
         };
         
         Self {
@@ -33,50 +39,114 @@ impl DvdBounceMovement {
             x_direction_toggle: true,
             y_direction_toggle: true,
             area,
-            last_update: Utc::now().timestamp_millis(),
             friend_shape,
+            obstacles,
         }
     }
 
+    /// Returns true when the creature would overlap with one of the obstacles at the given location.
+    fn hits_obstacle(&self, location: Location) -> bool {
+        let (shape_width, shape_height) = self.friend_shape.get_dimensions();
+        let friend_bounds = Rect::new(
+            location.x as u16,
+            location.y as u16,
+            shape_width as u16,
+            shape_height as u16,
+        );
+
+        self.obstacles.iter().any(|obstacle| obstacle.intersects(friend_bounds))
+    }
+
     fn update_state(&mut self) {
         let (shape_width, shape_height) = self.friend_shape.get_dimensions();
 
-        let now = Utc::now().timestamp_millis();
+        // Always turn back towards the inside of the area, so the creature also finds its way
+        // back when it ended up outside of the area.
+        if self.location.x <= self.area.left() as u32 {
+            self.x_direction_toggle = true;
+        } else if self.location.x > (self.area.right() as u32).saturating_sub(shape_width) {
+            self.x_direction_toggle = false;
+        }
+        
+        if self.location.y <= self.area.top() as u32 {
+            self.y_direction_toggle = true;
+        } else if self.location.y > (self.area.bottom() as u32).saturating_sub(shape_height) {
+            self.y_direction_toggle = false;
+        }
 
-        if now - self.last_update > 500 {
-            self.last_update = now;
-            
-            if self.location.x <= self.area.left() as u32
-                || self.location.x > self.area.right() as u32 - shape_width
-            {
+        let mut new_x = if self.x_direction_toggle {
+            self.location.x + 1
+        } else {
+            self.location.x - 1
+        };
+        let mut new_y = if self.y_direction_toggle {
+            self.location.y + 1
+        } else {
+            self.location.y - 1
+        };
+
+        // Bounce off of obstacles, unless we are already stuck inside one and have to get out first.
+        if !self.hits_obstacle(self.location) {
+            if self.hits_obstacle(Location::new(new_x, self.location.y)) {
                 self.x_direction_toggle = !self.x_direction_toggle;
+                new_x = self.location.x;
             }
-            
-            if self.location.y <= self.area.top() as u32
-                || self.location.y > self.area.bottom() as u32 - shape_height
-            {
+            if self.hits_obstacle(Location::new(self.location.x, new_y)) {
                 self.y_direction_toggle = !self.y_direction_toggle;
+                new_y = self.location.y;
             }
-
-            let new_x = if self.x_direction_toggle {
-                self.location.x + 1
-            } else {
-                self.location.x - 1
-            };
-            let new_y = if self.y_direction_toggle {
-                self.location.y + 1
-            } else {
-                self.location.y - 1
-            };
-
-            self.location = Location::new(new_x, new_y);
         }
+
+        self.location = Location::new(new_x, new_y);
     }
 }
 
 impl Movement for DvdBounceMovement {
-    fn next_position(&mut self) -> Location {
+    fn tick(&mut self) {
         self.update_state();
+    }
+
+    fn position(&self) -> Location {
         self.location
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+    use ratatui::style::Color;
+    use crate::movements::{DvdBounceMovement, Movement};
+    use crate::shapes::PixelVectorShape;
+    use crate::utils::Pixel;
+    use crate::utils::location::Location;
+
+    #[test]
+    fn returns_to_a_smaller_area() {
+        let mut movement = DvdBounceMovement::new(Location::new(50, 50), Rect::new(0, 0, 100, 100), PixelVectorShape::new(vec![]), vec![]);
+        // The terminal got smaller, leaving the creature outside of its area.
+        movement.area = Rect::new(0, 0, 20, 20);
+        movement.location = Location::new(60, 60);
+
+        for _ in 0..10 {
+            movement.tick();
+        }
+        assert_eq!(movement.location, Location::new(50, 50));
+    }
+
+    #[test]
+    fn bounces_off_items() {
+        let pixel = PixelVectorShape::new(vec![Pixel { x: 0, y: 0, color: Color::White }]);
+        // An item standing in the way, like a wall from the top to the bottom of the playground.
+        let item = Rect::new(15, 0, 5, 100);
+        let mut movement = DvdBounceMovement::new(Location::new(50, 50), Rect::new(0, 0, 100, 100), pixel, vec![item]);
+        movement.location = Location::new(12, 50);
+        movement.x_direction_toggle = true;
+
+        for _ in 0..10 {
+            movement.tick();
+            assert!(movement.location.x < u32::from(item.left()));
+        }
+        assert!(!movement.x_direction_toggle);
+        assert_eq!(movement.location.x, 7);
+    }
+}
@@ -1,42 +1,35 @@
 use super::movement::Movement;
-use chrono::Utc;
+use crate::shapes::PixelVectorShape;
 use crate::utils::location::Location;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct EggHopMovement {
     start_location: Location,
     is_grounded: bool,
-    last_update: i64,
 }
 
 impl EggHopMovement {
-    pub fn new(start_location: Location) -> Self {
-        // Sprite has a resolution of 10x10 pixels.
+    pub fn new(start_location: Location, friend_shape: &PixelVectorShape) -> Self {
+        // Center the sprite on the start location.
+        let (width, height) = friend_shape.get_dimensions();
         let updated_location = Location {
-            x: start_location.x - 5,
-            y: start_location.y - 5
+            x: start_location.x.saturating_sub(width / 2),
+            y: start_location.y.saturating_sub(height / 2)
         };
         
         Self {
             start_location: updated_location,
             is_grounded: true,
-            last_update: Utc::now().timestamp_millis(),
-        }
-    }
-    
-    fn try_update_state(&mut self) {
-        let now = Utc::now().timestamp_millis();
-        if now - self.last_update > 500 { // half a second
-            self.is_grounded = !self.is_grounded;
-            self.last_update = now;
         }
     }
 }
 
 impl Movement for EggHopMovement {
-    fn next_position(&mut self) -> Location {
-        self.try_update_state();
-        
+    fn tick(&mut self) {
+        self.is_grounded = !self.is_grounded;
+    }
+
+    fn position(&self) -> Location {
         if self.is_grounded {
             self.start_location
         } else {
@@ -7,8 +7,11 @@ use super::{
 
 
 pub trait Movement {
-    /// Updates the state of the movement and returns the new location.
-    fn next_position(&mut self) -> Location;
+    /// Takes the next step of the movement. This is called once every logic tick of the game.
+    fn tick(&mut self);
+
+    /// Returns the current location.
+    fn position(&self) -> Location;
 }
 
 
@@ -19,11 +22,19 @@ pub enum MovementWrapper {
     DvdBounce(DvdBounceMovement),
 }
 impl Movement for MovementWrapper {
-    fn next_position(&mut self) -> Location {
+    fn tick(&mut self) {
+        match self {
+            MovementWrapper::EggHop(movement) => movement.tick(),
+            MovementWrapper::SmallSteps(movement) => movement.tick(),
+            MovementWrapper::DvdBounce(movement) => movement.tick(),
+        }
+    }
+
+    fn position(&self) -> Location {
         match self {
-            MovementWrapper::EggHop(movement) => movement.next_position(),
-            MovementWrapper::SmallSteps(movement) => movement.next_position(),
-            MovementWrapper::DvdBounce(movement) => movement.next_position(),
+            MovementWrapper::EggHop(movement) => movement.position(),
+            MovementWrapper::SmallSteps(movement) => movement.position(),
+            MovementWrapper::DvdBounce(movement) => movement.position(),
         }
     }
 }
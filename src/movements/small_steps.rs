@@ -1,5 +1,5 @@
-use chrono::Utc;
 use crate::movements::Movement;
+use crate::shapes::PixelVectorShape;
 use crate::utils::location::Location;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -9,14 +9,14 @@ pub struct SmallStepsMovement {
     y_move: i32,
     x_toggle: bool,
     y_toggle: bool,
-    last_update: i64,
 }
 
 impl SmallStepsMovement {
-    pub fn new(initial_location: Location) -> Self {
+    pub fn new(initial_location: Location, friend_shape: &PixelVectorShape) -> Self {
+        let (width, height) = friend_shape.get_dimensions();
         let updated_location = Location {
-            x: initial_location.x - 7, // 7 for sprite dimensions (15x15)
-            y: initial_location.y - 7,
+            x: initial_location.x.saturating_sub(width / 2), // Centers the sprite on the initial location.
+            y: initial_location.y.saturating_sub(height / 2),
         };
         
         Self {
@@ -25,33 +25,27 @@ impl SmallStepsMovement {
             y_move: 0,
             x_toggle: true,
             y_toggle: true,
-            last_update: Utc::now().timestamp_millis(),
         }
     }
     
     fn update_state(&mut self) {
-        let now = Utc::now().timestamp_millis();
-        if now - self.last_update > 500 { // half a second
-            self.last_update = now;
-            
-            if self.x_move == 10 || self.x_move == -10 {
-                self.x_toggle = !self.x_toggle;
-            }
-            if self.y_move == 2 || self.y_move == -2 {
-                self.y_toggle = !self.y_toggle;
-            }
+        if self.x_move == 10 || self.x_move == -10 {
+            self.x_toggle = !self.x_toggle;
+        }
+        if self.y_move == 2 || self.y_move == -2 {
+            self.y_toggle = !self.y_toggle;
+        }
 
-            if self.x_toggle {
-                self.x_move += 1;
-            } else {
-                self.x_move -= 1;
-            }
-            
-            if self.y_toggle {
-                self.y_move += 1;
-            } else {
-                self.y_move -= 1;
-            }
+        if self.x_toggle {
+            self.x_move += 1;
+        } else {
+            self.x_move -= 1;
+        }
+        
+        if self.y_toggle {
+            self.y_move += 1;
+        } else {
+            self.y_move -= 1;
         }
     }
     
@@ -72,8 +66,11 @@ impl SmallStepsMovement {
 }
 
 impl Movement for SmallStepsMovement {
-    fn next_position(&mut self) -> Location {
+    fn tick(&mut self) {
         self.update_state();
+    }
+
+    fn position(&self) -> Location {
         self.get_location()
     }
 }
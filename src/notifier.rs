@@ -0,0 +1,185 @@
+use std::process::Command;
+use std::thread::sleep;
+use std::time::Duration;
+use notify_rust::Notification;
+use crate::config::Config;
+use crate::friend::{Friend, StatKind, STAT_KINDS};
+use crate::daemon::Session;
+use crate::utils::file_logging::log_to_file;
+
+/// The shortest time the notifier waits before checking on the friend again.
+const MIN_CHECK_INTERVAL_MILLIS: i64 = 1000 * 30;
+/// The longest time the notifier waits before checking on the friend again, so it notices when
+/// the player took care of their pet in the meantime.
+const MAX_CHECK_INTERVAL_MILLIS: i64 = 1000 * 60 * 15;
+
+/// Keeps an eye on the friend while the game isn't open, and notifies the player once one of its stats
+/// runs low. It sleeps until the next stat is expected to become critical, based on the decay of the stats.
+/// <br>
+/// ## parameters:
+/// * `config` - The settings of the player, which hold the critical threshold and the notify command.
+pub fn run_notifier(config: &Config) -> std::io::Result<()> {
+    let mut notifier = Notifier::new(config);
+
+    loop {
+        let mut session = Session::open_read_only()?;
+        session.refresh()?;
+        let friend = session.game_state().friend();
+        let now = session.game_state().now();
+
+        // A missing notification daemon or a failing notify command shouldn't stop the notifier.
+        for error in notifier.check(friend) {
+            let _ = log_to_file(&format!("Failed to send a notification: {error}"));
+        }
+
+        let next_check = friend.next_critical(config.critical_threshold)
+            .map(|(_, time)| time - now)
+            .unwrap_or(MAX_CHECK_INTERVAL_MILLIS)
+            .clamp(MIN_CHECK_INTERVAL_MILLIS, MAX_CHECK_INTERVAL_MILLIS);
+        sleep(Duration::from_millis(next_check as u64));
+    }
+}
+
+/// Remembers which notifications were already sent, so the player is only notified once
+/// every time a stat becomes critical.
+struct Notifier<'a> {
+    config: &'a Config,
+    notified: Vec<StatKind>,
+    notified_death: bool,
+}
+
+impl<'a> Notifier<'a> {
+    fn new(config: &'a Config) -> Self {
+        Self {
+            config,
+            notified: Vec::new(),
+            notified_death: false,
+        }
+    }
+
+    /// Checks on the friend once, and notifies the player of every stat that became critical or of its death.
+    /// <br>
+    /// ## parameters:
+    /// * `friend` - The friend to check on.
+    ///
+    /// ## returns:
+    /// The errors of the notifications that couldn't be sent, they aren't sent again.
+    fn check(&mut self, friend: &Friend) -> Vec<std::io::Error> {
+        let mut errors = Vec::new();
+
+        if !*friend.alive() {
+            if !self.notified_death {
+                errors.extend(send_notification(self.config, "Termagotchi", &format!("{} has died...", friend.name())).err());
+                self.notified_death = true;
+            }
+        } else {
+            self.notified_death = false;
+            for kind in critical_stats(friend, self.config.critical_threshold) {
+                if !self.notified.contains(&kind) {
+                    errors.extend(send_notification(self.config, "Termagotchi", &critical_message(friend, kind)).err());
+                    self.notified.push(kind);
+                }
+            }
+        }
+        // Notify again when the stat becomes critical after the player took care of it.
+        self.notified.retain(|kind| friend.stat(*kind).value() <= self.config.critical_threshold);
+        errors
+    }
+}
+
+/// Returns all stats of the friend that are at or below the threshold.
+/// <br>
+/// ## parameters:
+/// * `friend` - The friend to check.
+/// * `threshold` - The value at which a stat becomes critical.
+pub fn critical_stats(friend: &Friend, threshold: u32) -> Vec<StatKind> {
+    STAT_KINDS.iter()
+        .filter(|kind| friend.stat(**kind).value() <= threshold)
+        .copied()
+        .collect()
+}
+
+fn critical_message(friend: &Friend, kind: StatKind) -> String {
+    format!("{} is running low on {} ({}%)", friend.name(), kind.name(), friend.stat(kind).value())
+}
+
+/// Shows a desktop notification, or runs the notify command from the config when there is one.
+/// <br>
+/// ## parameters:
+/// * `config` - The settings of the player.
+/// * `title` - The title of the notification.
+/// * `message` - The text of the notification.
+pub fn send_notification(config: &Config, title: &str, message: &str) -> std::io::Result<()> {
+    if let Some(command) = &config.notify_command {
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .env("TERMAGOTCHI_TITLE", title)
+            .env("TERMAGOTCHI_MESSAGE", message)
+            .status()?;
+
+        if !status.success() {
+            return Err(std::io::Error::other(format!("notify command failed with {status}")));
+        }
+        return Ok(());
+    }
+
+    Notification::new()
+        .appname("Termagotchi")
+        .summary(title)
+        .body(message)
+        .show()
+        .map(|_| ())
+        .map_err(std::io::Error::other)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+    use crate::environment::Environment;
+    use crate::friend::{Friend, StatKind};
+    use crate::notifier::{critical_stats, send_notification, Notifier};
+    use crate::shapes::creatures::CreatureShapes;
+    use crate::utils::ColorWrapper;
+
+    #[test]
+    fn notify_command_receives_message() {
+        let output = std::env::temp_dir().join(format!("termagotchi-notify-{}", std::process::id()));
+        let config = Config {
+            notify_command: Some(format!("printf '%s: %s' \"$TERMAGOTCHI_TITLE\" \"$TERMAGOTCHI_MESSAGE\" > {}", output.display())),
+            ..Config::default()
+        };
+
+        send_notification(&config, "Termagotchi", "duck is running low on food (20%)").unwrap();
+        let written = std::fs::read_to_string(&output).unwrap();
+        std::fs::remove_file(&output).unwrap();
+
+        assert_eq!(written, "Termagotchi: duck is running low on food (20%)");
+    }
+
+    #[test]
+    fn failing_notifications_keep_the_notifier_running() {
+        let config = Config {
+            notify_command: Some(String::from("exit 1")),
+            ..Config::default()
+        };
+        let mut notifier = Notifier::new(&config);
+        let environment = Environment { lights_on: true, weather_seed: 0, has_bed: false };
+        let hour = 1000 * 60 * 60;
+
+        let mut friend = Friend::born_at("duck", CreatureShapes::Duck(ColorWrapper::Red), 0);
+        friend.update_state(9 * hour, &environment);
+        let critical = critical_stats(&friend, config.critical_threshold);
+        assert!(critical.contains(&StatKind::Food));
+        assert_eq!(notifier.check(&friend).len(), critical.len());
+        assert_eq!(notifier.notified, critical);
+        assert!(notifier.check(&friend).is_empty());
+
+        // The notifier still notices the friend dying after a notification failed.
+        friend.update_state(48 * hour, &environment);
+        assert!(!*friend.alive());
+        assert!(!notifier.check(&friend).is_empty());
+        assert!(notifier.notified_death);
+    }
+}
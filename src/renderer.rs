@@ -0,0 +1,334 @@
+use std::sync::OnceLock;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::symbols::Marker;
+use ratatui::widgets::{Block, Widget};
+use ratatui::widgets::canvas::{Canvas, Context};
+use serde::{Deserialize, Serialize};
+
+static RENDERER: OnceLock<Renderer> = OnceLock::new();
+
+/// The quadrant characters, by which of the 4 dots of a cell are drawn.
+/// The dots are numbered left to right and top to bottom, the first dot is the lowest bit.
+const QUADRANTS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+];
+/// The first of the sextant characters, which are ordered by which of the 6 dots of a cell are drawn.
+/// The sextants that look like a half or full block aren't part of them.
+const FIRST_SEXTANT: u32 = 0x1FB00;
+const LEFT_HALF_SEXTANT: usize = 0b010101;
+const RIGHT_HALF_SEXTANT: usize = 0b101010;
+
+/// The characters the sprites are drawn with. Braille has the highest resolution,
+/// but some fonts draw braille characters badly, so the player can pick blocks instead.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Renderer {
+    /// Draws 2 by 4 dots in every cell, using braille dots.
+    #[default]
+    Braille,
+    /// Draws 2 by 3 dots in every cell, using sextant blocks.
+    Sextant,
+    /// Draws 2 by 2 dots in every cell, using quadrant blocks.
+    Quadrant,
+    /// Draws 1 by 2 dots in every cell, using half blocks.
+    HalfBlock,
+    /// Draws a single dot in every cell by filling the whole cell, which works with every font.
+    Block,
+}
+
+impl Renderer {
+    /// Sets the renderer used by the whole game. Only the first call has an effect.
+    pub fn set_global(renderer: Renderer) {
+        let _ = RENDERER.set(renderer);
+    }
+
+    /// Returns the renderer used by the whole game, which is braille when none was set.
+    pub fn global() -> Renderer {
+        *RENDERER.get_or_init(Renderer::default)
+    }
+
+    /// Returns the marker ratatui draws with. Ratatui has no marker for quadrants and sextants,
+    /// charts use half blocks for them, and canvases are drawn by `RendererCanvas` instead.
+    pub fn marker(&self) -> Marker {
+        match self {
+            Renderer::Braille => Marker::Braille,
+            Renderer::HalfBlock | Renderer::Quadrant | Renderer::Sextant => Marker::HalfBlock,
+            Renderer::Block => Marker::Block,
+        }
+    }
+
+    /// Returns how many dots the renderer draws in one cell of the terminal, horizontally and vertically.
+    pub fn dots_per_cell(&self) -> (u16, u16) {
+        match self {
+            Renderer::Braille => (2, 4),
+            Renderer::Sextant => (2, 3),
+            Renderer::Quadrant => (2, 2),
+            Renderer::HalfBlock => (1, 2),
+            Renderer::Block => (1, 1),
+        }
+    }
+
+    /// Returns how many pixels of a sprite fit in one cell of the terminal, horizontally and vertically.
+    /// Cells are about twice as high as they are wide, so there are twice as many pixels vertically
+    /// to keep the pixels square. A pixel covers more than one dot when the dots aren't square, like
+    /// a block that takes two cells, and shares a dot with other pixels when the dots are larger.
+    pub fn pixels_per_cell(&self) -> (f64, f64) {
+        match self {
+            Renderer::Braille | Renderer::Sextant | Renderer::Quadrant => (2.0, 4.0),
+            Renderer::HalfBlock => (1.0, 2.0),
+            Renderer::Block => (0.5, 1.0),
+        }
+    }
+
+    /// Returns a canvas that draws with this renderer.
+    /// <br>
+    /// ## parameters:
+    /// * `x_bounds` - The horizontal bounds of the canvas, like those of `Canvas::x_bounds`.
+    /// * `y_bounds` - The vertical bounds of the canvas, like those of `Canvas::y_bounds`.
+    /// * `paint` - Draws the shapes on the canvas, like the function passed to `Canvas::paint`.
+    pub fn canvas<'a, F: Fn(&mut Context)>(&self, x_bounds: [f64; 2], y_bounds: [f64; 2], paint: F) -> RendererCanvas<'a, F> {
+        RendererCanvas {
+            renderer: *self,
+            block: None,
+            background_color: Color::Reset,
+            x_bounds,
+            y_bounds,
+            paint,
+        }
+    }
+}
+
+/// ## RendererCanvas
+/// A canvas that is drawn with one of the renderers. Ratatui's canvas draws braille and (half) blocks,
+/// but it has no markers for quadrants and sextants. Those are drawn on a hidden half block canvas
+/// with the same number of dots, which is then turned into quadrant or sextant characters.
+pub struct RendererCanvas<'a, F: Fn(&mut Context)> {
+    renderer: Renderer,
+    block: Option<Block<'a>>,
+    background_color: Color,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+    paint: F,
+}
+
+impl<'a, F: Fn(&mut Context)> RendererCanvas<'a, F> {
+    /// Draws the given block around the canvas.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Fills the canvas with the given color, before anything is drawn on it.
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = color;
+        self
+    }
+}
+
+impl<F: Fn(&mut Context)> Widget for RendererCanvas<'_, F> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = match &self.block {
+            Some(block) => {
+                block.render(area, buf);
+                block.inner(area)
+            },
+            None => area,
+        };
+        if area.is_empty() {
+            return;
+        }
+
+        if !matches!(self.renderer, Renderer::Quadrant | Renderer::Sextant) {
+            Canvas::default()
+                .marker(self.renderer.marker())
+                .background_color(self.background_color)
+                .x_bounds(self.x_bounds)
+                .y_bounds(self.y_bounds)
+                .paint(self.paint)
+                .render(area, buf);
+            return;
+        }
+
+        // Every cell of the half block canvas holds 2 dots above each other, so it needs twice as many
+        // columns. When the rows of dots don't fit exactly, the bounds are stretched over the extra row at the bottom.
+        let (columns, rows) = self.renderer.dots_per_cell();
+        let dot_rows = area.height * rows;
+        let hidden_area = Rect::new(0, 0, area.width * columns, dot_rows.div_ceil(2));
+        let extra_rows = hidden_area.height * 2 - dot_rows;
+        let [bottom, top] = self.y_bounds;
+        let bottom = bottom - (top - bottom) * f64::from(extra_rows) / f64::from(dot_rows);
+
+        let mut hidden = Buffer::empty(hidden_area);
+        Canvas::default()
+            .marker(Marker::HalfBlock)
+            .background_color(self.background_color)
+            .x_bounds(self.x_bounds)
+            .y_bounds([bottom, top])
+            .paint(self.paint)
+            .render(hidden_area, &mut hidden);
+
+        for row in 0..area.height {
+            for column in 0..area.width {
+                let dots: Vec<Color> = (0..rows)
+                    .flat_map(|dot_row| (0..columns).map(move |dot_column| (dot_column, dot_row)))
+                    .map(|(dot_column, dot_row)| half_block_dot(&hidden, column * columns + dot_column, row * rows + dot_row))
+                    .collect();
+
+                let (symbol, foreground, background) = glyph(&dots, self.background_color);
+                buf[(area.x + column, area.y + row)]
+                    .set_char(symbol)
+                    .set_fg(foreground)
+                    .set_bg(background);
+            }
+        }
+    }
+}
+
+/// Returns the color of a dot drawn on a half block canvas.
+/// <br>
+/// ## parameters:
+/// * `buffer` - The buffer the half block canvas was drawn in.
+/// * `column` - The column of the dot, which is also the column of its cell.
+/// * `row` - The row of the dot from the top, there are two rows of dots in every cell.
+fn half_block_dot(buffer: &Buffer, column: u16, row: u16) -> Color {
+    let cell = &buffer[(column, row / 2)];
+    let upper = row.is_multiple_of(2);
+    match cell.symbol() {
+        "▀" if upper => cell.fg,
+        "▄" if !upper => cell.fg,
+        "█" => cell.fg,
+        _ => cell.bg,
+    }
+}
+
+/// Returns the quadrant or sextant character that draws the given dots, with its foreground and background color.
+/// A cell can only show two colors, so the background of the canvas stays the background wherever it shows,
+/// otherwise the most common color becomes the background. Every other dot gets the most common of the remaining colors.
+/// <br>
+/// ## parameters:
+/// * `dots` - The colors of the 4 or 6 dots of a cell, left to right and top to bottom.
+/// * `canvas_background` - The background color of the canvas.
+fn glyph(dots: &[Color], canvas_background: Color) -> (char, Color, Color) {
+    let most_common = |colors: &mut dyn Iterator<Item = Color>| {
+        let colors: Vec<Color> = colors.collect();
+        colors.iter()
+            .max_by_key(|color| colors.iter().filter(|other| other == color).count())
+            .copied()
+    };
+
+    let background = match dots.contains(&canvas_background) {
+        true => canvas_background,
+        false => most_common(&mut dots.iter().copied()).unwrap_or(canvas_background),
+    };
+    let Some(foreground) = most_common(&mut dots.iter().copied().filter(|dot| *dot != background)) else {
+        return (' ', background, background);
+    };
+
+    let mask = dots.iter()
+        .enumerate()
+        .filter(|(_, dot)| **dot != background)
+        .fold(0, |mask, (index, _)| mask | 1 << index);
+
+    let symbol = if dots.len() == 4 {
+        QUADRANTS[mask]
+    } else {
+        match mask {
+            LEFT_HALF_SEXTANT => '▌',
+            RIGHT_HALF_SEXTANT => '▐',
+            0b111111 => '█',
+            _ => {
+                let skipped = usize::from(mask > LEFT_HALF_SEXTANT) + usize::from(mask > RIGHT_HALF_SEXTANT);
+                char::from_u32(FIRST_SEXTANT + (mask - 1 - skipped) as u32).unwrap_or('█')
+            },
+        }
+    };
+    (symbol, foreground, background)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::{Buffer, Cell};
+    use ratatui::layout::Rect;
+    use ratatui::style::Color;
+    use ratatui::widgets::Widget;
+    use ratatui::widgets::canvas::Points;
+    use crate::renderer::Renderer;
+    use crate::shapes::PixelVectorShape;
+    use crate::utils::Pixel;
+
+    const RENDERERS: [Renderer; 5] = [Renderer::Braille, Renderer::Sextant, Renderer::Quadrant, Renderer::HalfBlock, Renderer::Block];
+
+    /// Draws the given dots with the renderer in a canvas of a single cell, and returns that cell.
+    fn draw_cell(renderer: Renderer, dots: &[(f64, f64)]) -> Cell {
+        let (columns, rows) = renderer.dots_per_cell();
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buffer = Buffer::empty(area);
+        renderer
+            .canvas([0.0, f64::from(columns)], [0.0, f64::from(rows)], |ctx| {
+                ctx.draw(&Points { coords: dots, color: Color::White })
+            })
+            .render(area, &mut buffer);
+        buffer[(0, 0)].clone()
+    }
+
+    #[test]
+    fn dots_per_cell_match_the_characters() {
+        for renderer in RENDERERS {
+            let (columns, rows) = renderer.dots_per_cell();
+            let all: Vec<(f64, f64)> = (0..=columns * 10)
+                .flat_map(|x| (0..=rows * 10).map(move |y| (f64::from(x) / 10.0, f64::from(y) / 10.0)))
+                .collect();
+
+            // A single dot only fills the whole cell when the renderer draws one dot per cell.
+            let full = draw_cell(renderer, &all);
+            let single = draw_cell(renderer, &[(0.0, 0.0)]);
+            assert_eq!(single == full, (columns, rows) == (1, 1), "{renderer:?}");
+        }
+
+        let renderer: Renderer = serde_json::from_str("\"half_block\"").unwrap();
+        assert_eq!(renderer, Renderer::HalfBlock);
+    }
+
+    #[test]
+    fn quadrants_and_sextants_draw_their_dots() {
+        // The lowest dot on the left side of the cell.
+        assert_eq!(draw_cell(Renderer::Quadrant, &[(0.0, 0.0)]).symbol(), "▖");
+        assert_eq!(draw_cell(Renderer::Sextant, &[(0.0, 0.0)]).symbol(), "\u{1FB0F}");
+        assert_eq!(draw_cell(Renderer::Sextant, &[(0.0, 0.0), (0.0, 1.5), (0.0, 3.0)]).symbol(), "▌");
+
+        // Three dots of the same color are drawn, while the fourth dot shows the background.
+        let cell = draw_cell(Renderer::Quadrant, &[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]);
+        assert_eq!((cell.symbol(), cell.fg, cell.bg), ("▟", Color::White, Color::Reset));
+    }
+
+    #[test]
+    fn pixels_stay_square() {
+        for renderer in RENDERERS {
+            let (columns, rows) = renderer.pixels_per_cell();
+            assert_eq!(rows, columns * 2.0, "{renderer:?}");
+        }
+    }
+
+    #[test]
+    fn block_pixels_take_two_cells() {
+        let renderer = Renderer::Block;
+        let area = Rect::new(0, 0, 10, 2);
+        let (columns, rows) = renderer.pixels_per_cell();
+        let (width, height) = (f64::from(area.width) * columns, f64::from(area.height) * rows);
+        let pixel = PixelVectorShape::new(vec![Pixel { x: 2, y: 0, color: Color::White }]);
+
+        let mut buffer = Buffer::empty(area);
+        renderer
+            .canvas([0.0, width], [0.0, height], |ctx| ctx.draw(&pixel))
+            .render(area, &mut buffer);
+
+        let drawn: Vec<(u16, u16)> = area.positions()
+            .filter(|position| buffer[*position].symbol() != " ")
+            .map(|position| (position.x, position.y))
+            .collect();
+        assert_eq!(drawn, vec![(3, 1), (4, 1)]);
+    }
+}
@@ -0,0 +1,60 @@
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::Path;
+
+/// ## SaveLock
+/// An advisory lock on the save file, only the instance of the game holding the lock is allowed to write the save file.
+/// The lock is released when the `SaveLock` is dropped, or when the process holding it exits.
+pub struct SaveLock {
+    _file: File,
+}
+
+impl SaveLock {
+    /// Tries to lock the save file, with a lock file next to it that has the `lock` extension.
+    /// <br>
+    /// ## parameters:
+    /// * `save_file` - The path of the save file.
+    ///
+    /// ## returns:
+    /// * `Some(SaveLock)` - When the save file was locked.
+    /// * `None` - When another instance of the game already holds the lock.
+    pub fn try_acquire(save_file: &Path) -> std::io::Result<Option<Self>> {
+        Self::try_acquire_at(&save_file.with_extension("lock"))
+    }
+
+    /// Tries to lock the save file, using the lock file at the given path.
+    /// <br>
+    /// ## parameters:
+    /// * `path` - The path of the lock file, which is created when it doesn't exist yet.
+    pub fn try_acquire_at(path: &Path) -> std::io::Result<Option<Self>> {
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path)?;
+
+        match file.try_lock() {
+            Ok(()) => Ok(Some(Self { _file: file })),
+            Err(TryLockError::WouldBlock) => Ok(None),
+            Err(TryLockError::Error(error)) => Err(error),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::save_lock::SaveLock;
+
+    #[test]
+    fn only_one_instance_holds_the_lock() {
+        let path = std::env::temp_dir().join(format!("termagotchi-lock-{}", std::process::id()));
+
+        let first = SaveLock::try_acquire_at(&path).unwrap();
+        assert!(first.is_some());
+        assert!(SaveLock::try_acquire_at(&path).unwrap().is_none());
+
+        drop(first);
+        assert!(SaveLock::try_acquire_at(&path).unwrap().is_some());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -3,9 +3,16 @@ use crate::utils::{ColorWrapper, Pixel, sprite_management::load_sprite};
 use serde::{Deserialize, Serialize};
 use rand::Rng;
 use crate::load_embedded_sprite;
+use crate::shapes::scenery::Scenery;
+use crate::food::{Food, FOODS};
+use crate::friend::GrowthStage;
+use crate::species::{SpeciesMovement, SpeciesPack};
 
 const NUM_SHAPES: u32 = 8;
 
+/// The names of all species a creature can be.
+pub const SPECIES: [&str; NUM_SHAPES as usize] = ["Duck", "Turtle", "Spider", "Snail", "Fish", "Mouse", "Frog", "Squid"];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CreatureShapes {
     Duck(ColorWrapper),
@@ -16,6 +23,9 @@ pub enum CreatureShapes {
     Mouse(ColorWrapper),
     Frog(ColorWrapper),
     Squid(ColorWrapper),
+    /// A species from a species pack, referred to by the name of the pack's directory.
+    /// Creatures whose pack is no longer installed look like a kid, and eat and behave like the built-in species.
+    Custom(String, ColorWrapper),
 }
 
 
@@ -30,16 +40,21 @@ impl PixelImage for CreatureShapes {
             CreatureShapes::Mouse(color) => load_embedded_sprite!("../../assets/mouse.png", color),
             CreatureShapes::Frog(color) => load_embedded_sprite!("../../assets/frog.png", color),
             CreatureShapes::Squid(color) => load_embedded_sprite!("../../assets/squid.png", color),
+            CreatureShapes::Custom(id, color) => SpeciesPack::find(id)
+                .and_then(|pack| pack.pixels(GrowthStage::Adult, *color))
+                .unwrap_or_else(|| load_embedded_sprite!("../../assets/kid.png", color)),
         }
     }
 }
 
 impl CreatureShapes {
+    /// Creates a creature of a random species and color, which can also be a species from an installed species pack.
     pub fn new_random() -> Self {
         let color = ColorWrapper::new_random();
         let mut rng = rand::thread_rng();
+        let packs = SpeciesPack::installed();
         
-        match rng.gen_range(0..NUM_SHAPES) {
+        match rng.gen_range(0..NUM_SHAPES as usize + packs.len()) {
             0 => CreatureShapes::Duck(color),
             1 => CreatureShapes::Turtle(color),
             2 => CreatureShapes::Spider(color),
@@ -47,7 +62,8 @@ impl CreatureShapes {
             4 => CreatureShapes::Fish(color),
             5 => CreatureShapes::Mouse(color),
             6 => CreatureShapes::Frog(color),
-            _ => CreatureShapes::Squid(color),
+            7 => CreatureShapes::Squid(color),
+            pack => CreatureShapes::Custom(String::from(packs[pack - NUM_SHAPES as usize].id()), color),
         }
     }
     
@@ -61,6 +77,77 @@ impl CreatureShapes {
             CreatureShapes::Mouse(color) => *color,
             CreatureShapes::Frog(color) => *color,
             CreatureShapes::Squid(color)  => *color,
+            CreatureShapes::Custom(_, color) => *color,
+        }
+    }
+
+    /// Returns the same creature, but with a different color.
+    pub fn with_color(&self, color: ColorWrapper) -> Self {
+        match self {
+            CreatureShapes::Duck(_) => CreatureShapes::Duck(color),
+            CreatureShapes::Turtle(_) => CreatureShapes::Turtle(color),
+            CreatureShapes::Spider(_) => CreatureShapes::Spider(color),
+            CreatureShapes::Snail(_) => CreatureShapes::Snail(color),
+            CreatureShapes::Fish(_) => CreatureShapes::Fish(color),
+            CreatureShapes::Mouse(_) => CreatureShapes::Mouse(color),
+            CreatureShapes::Frog(_) => CreatureShapes::Frog(color),
+            CreatureShapes::Squid(_) => CreatureShapes::Squid(color),
+            CreatureShapes::Custom(id, _) => CreatureShapes::Custom(id.clone(), color),
+        }
+    }
+
+    /// Returns the name of this creature's species, without its color.
+    pub fn species(&self) -> &str {
+        match self {
+            CreatureShapes::Duck(_) => SPECIES[0],
+            CreatureShapes::Turtle(_) => SPECIES[1],
+            CreatureShapes::Spider(_) => SPECIES[2],
+            CreatureShapes::Snail(_) => SPECIES[3],
+            CreatureShapes::Fish(_) => SPECIES[4],
+            CreatureShapes::Mouse(_) => SPECIES[5],
+            CreatureShapes::Frog(_) => SPECIES[6],
+            CreatureShapes::Squid(_) => SPECIES[7],
+            CreatureShapes::Custom(id, _) => SpeciesPack::find(id).map_or(id, |pack| &pack.manifest().name),
+        }
+    }
+
+    /// Returns true for creatures that enjoy the rain, instead of getting sad because of it.
+    pub fn likes_water(&self) -> bool {
+        if let CreatureShapes::Custom(id, _) = self {
+            return SpeciesPack::find(id).is_some_and(|pack| pack.manifest().likes_water);
+        }
+
+        matches!(
+            self,
+            CreatureShapes::Duck(_) | CreatureShapes::Fish(_) | CreatureShapes::Frog(_)
+                | CreatureShapes::Squid(_) | CreatureShapes::Snail(_)
+        )
+    }
+
+    /// Returns the scenery that best suits the natural habitat of this creature.
+    pub fn habitat(&self) -> Scenery {
+        match self {
+            CreatureShapes::Duck(_) | CreatureShapes::Fish(_) | CreatureShapes::Frog(_) => Scenery::Pond,
+            CreatureShapes::Spider(_) => Scenery::WebCorner,
+            CreatureShapes::Snail(_) | CreatureShapes::Turtle(_) => Scenery::Grass,
+            CreatureShapes::Mouse(_) | CreatureShapes::Squid(_) => Scenery::Plain,
+            CreatureShapes::Custom(id, _) => SpeciesPack::find(id).map_or(Scenery::Plain, |pack| pack.manifest().habitat),
+        }
+    }
+
+    /// Returns the food this creature eats.
+    pub fn diet(&self) -> Vec<Food> {
+        match self {
+            CreatureShapes::Custom(id, _) => SpeciesPack::find(id).map_or(FOODS.to_vec(), |pack| pack.diet()),
+            _ => FOODS.to_vec(),
+        }
+    }
+
+    /// Returns how this creature moves around the playground once it is an adult.
+    pub fn movement(&self) -> SpeciesMovement {
+        match self {
+            CreatureShapes::Custom(id, _) => SpeciesPack::find(id).map_or_else(SpeciesMovement::default, |pack| pack.manifest().movement),
+            _ => SpeciesMovement::Bounce,
         }
     }
 }
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use crate::shapes::PixelImage;
 use crate::utils::{ColorWrapper, Pixel, sprite_management::load_sprite};
 use crate::load_embedded_sprite;
+use crate::friend::GrowthStage;
+use crate::species::SpeciesPack;
 
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -9,6 +11,9 @@ pub enum GrowthStageShapes {
     Egg(ColorWrapper),
     Baby(ColorWrapper),
     Kid(ColorWrapper),
+    /// A growth stage of a species from a species pack, which looks like the other growth stages
+    /// when the pack has no sprite for it.
+    Custom(String, GrowthStage, ColorWrapper),
 }
 
 impl PixelImage for GrowthStageShapes {
@@ -17,6 +22,13 @@ impl PixelImage for GrowthStageShapes {
             GrowthStageShapes::Egg(color) => load_embedded_sprite!("../../assets/egg.png", color),
             GrowthStageShapes::Baby(color) => load_embedded_sprite!("../../assets/baby.png", color),
             GrowthStageShapes::Kid(color) => load_embedded_sprite!("../../assets/kid.png", color),
+            GrowthStageShapes::Custom(id, stage, color) => SpeciesPack::find(id)
+                .and_then(|pack| pack.pixels(*stage, *color))
+                .unwrap_or_else(|| match stage {
+                    GrowthStage::Egg => GrowthStageShapes::Egg(*color).pixels(),
+                    GrowthStage::Baby => GrowthStageShapes::Baby(*color).pixels(),
+                    _ => GrowthStageShapes::Kid(*color).pixels(),
+                }),
         }
     }
 }
\ No newline at end of file
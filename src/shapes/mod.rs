@@ -2,7 +2,12 @@ pub mod creatures;
 mod pixel_image;
 mod growth_stages;
 mod pixel_vector;
+mod sky;
+pub mod scenery;
+mod weather_particles;
 
 pub use pixel_vector::PixelVectorShape;
 pub use pixel_image::PixelImage;
 pub use growth_stages::GrowthStageShapes;
+pub use sky::SkyShape;
+pub use weather_particles::WeatherParticles;
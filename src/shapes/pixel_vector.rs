@@ -1,3 +1,4 @@
+use ratatui::style::Color;
 use ratatui::widgets::canvas::{Painter, Shape};
 use crate::shapes::PixelImage;
 use crate::utils::Pixel;
@@ -30,6 +31,18 @@ impl PixelVectorShape {
         self
     }
     
+    /// Changes the color of every pixel of the shape.
+    /// <br>
+    /// ## parameters:
+    /// * `recolor` - Returns the new color of a pixel, given its current color.
+    pub fn recolor(mut self, recolor: impl Fn(Color) -> Color) -> Self {
+        for pixel in &mut self.0 {
+            pixel.color = recolor(pixel.color);
+        }
+
+        self
+    }
+
     /// Get the dimensions of the shape as `(x, y)`. <br>
     /// Returns `(0, 0)` if the shape doesn't have any pixels.
     pub fn get_dimensions(&self) -> (u32, u32) {
@@ -99,8 +112,18 @@ impl PixelVectorShape {
 impl Shape for PixelVectorShape {
     fn draw(&self, painter: &mut Painter) {
         for pixel in &self.0 {
-            if let Some((x, y)) = painter.get_point(f64::from(pixel.x), f64::from(pixel.y)) {
-                painter.paint(x, y, pixel.color);
+            let (x, y) = (f64::from(pixel.x), f64::from(pixel.y));
+            let Some((left, bottom)) = painter.get_point(x, y) else {
+                continue;
+            };
+
+            // A pixel covers every dot up to the next pixel, so pixels larger than a dot leave no gaps.
+            // The rows of the painter go down, so the next pixel up has a lower row.
+            let (right, top) = painter.get_point(x + 1.0, y + 1.0).unwrap_or((left, bottom));
+            for column in left..right.max(left + 1) {
+                for row in (top + 1).min(bottom)..=bottom {
+                    painter.paint(column, row, pixel.color);
+                }
             }
         }
     }
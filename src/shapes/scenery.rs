@@ -0,0 +1,138 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+use ratatui::style::Color;
+use crate::theme::Theme;
+use ratatui::widgets::canvas::{Line, Painter, Shape};
+use serde::{Deserialize, Serialize};
+
+/// The different backgrounds that can be drawn behind the creature in the playground.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Scenery {
+    Plain,
+    Pond,
+    WebCorner,
+    Grass,
+}
+
+/// ## SceneryShape
+/// Draws a `Scenery` scaled to the size of the canvas it is drawn on.
+pub struct SceneryShape {
+    scenery: Scenery,
+    width: f64,
+    height: f64,
+}
+
+impl SceneryShape {
+    /// Creates a new `SceneryShape`.
+    /// <br>
+    /// ## parameters:
+    /// * `scenery` - The scenery that should be drawn.
+    /// * `width` - The width of the canvas the scenery is drawn on.
+    /// * `height` - The height of the canvas the scenery is drawn on.
+    pub fn new(scenery: Scenery, width: f64, height: f64) -> Self {
+        Self {
+            scenery,
+            width,
+            height,
+        }
+    }
+
+    fn ground_level(&self) -> f64 {
+        self.height * 0.08
+    }
+
+    fn draw_ground(&self, painter: &mut Painter, color: Color) {
+        let ground = self.ground_level();
+        Line::new(0.0, ground, self.width, ground, Theme::global().paint(color)).draw(painter);
+    }
+
+    fn draw_pond(&self, painter: &mut Painter) {
+        let (center_x, center_y) = (self.width * 0.75, self.ground_level() + self.height * 0.06);
+        let (radius_x, radius_y) = (self.width * 0.18, self.height * 0.05);
+
+        // Outline of the pond, drawn as a flattened circle.
+        let segments = 32;
+        for segment in 0..segments {
+            let start = f64::from(segment) / f64::from(segments) * TAU;
+            let end = f64::from(segment + 1) / f64::from(segments) * TAU;
+            Line::new(
+                center_x + radius_x * start.cos(), center_y + radius_y * start.sin(),
+                center_x + radius_x * end.cos(), center_y + radius_y * end.sin(),
+                Theme::global().paint(Color::LightBlue),
+            ).draw(painter);
+        }
+
+        // Some small waves on the water.
+        for (x_offset, y_offset) in [(-0.4, 0.2), (0.1, -0.3), (0.3, 0.4)] {
+            let x = center_x + radius_x * x_offset;
+            let y = center_y + radius_y * y_offset;
+            Line::new(x - 2.0, y, x, y + 1.0, Theme::global().paint(Color::Blue)).draw(painter);
+            Line::new(x, y + 1.0, x + 2.0, y, Theme::global().paint(Color::Blue)).draw(painter);
+        }
+
+        // Reeds next to the pond.
+        for x_offset in [-1.15, -1.08, 1.1] {
+            let x = center_x + radius_x * x_offset;
+            Line::new(x, self.ground_level(), x, self.ground_level() + self.height * 0.15, Theme::global().paint(Color::Green))
+                .draw(painter);
+        }
+    }
+
+    fn draw_web_corner(&self, painter: &mut Painter) {
+        let (corner_x, corner_y) = (0.0, self.height);
+        let length = self.width.min(self.height) * 0.35;
+        let threads = 5;
+        let rings = 3;
+
+        let point = |thread: u32, distance: f64| {
+            let angle = f64::from(thread) / f64::from(threads - 1) * FRAC_PI_2;
+            (corner_x + distance * angle.cos(), corner_y - distance * angle.sin())
+        };
+
+        for thread in 0..threads {
+            let (x, y) = point(thread, length);
+            Line::new(corner_x, corner_y, x, y, Theme::global().paint(Color::Gray)).draw(painter);
+        }
+
+        for ring in 1..=rings {
+            let distance = length * f64::from(ring) / f64::from(rings + 1);
+            for thread in 0..threads - 1 {
+                let (x1, y1) = point(thread, distance);
+                let (x2, y2) = point(thread + 1, distance);
+                Line::new(x1, y1, x2, y2, Theme::global().paint(Color::Gray)).draw(painter);
+            }
+        }
+    }
+
+    fn draw_grass(&self, painter: &mut Painter) {
+        let ground = self.ground_level();
+        let tufts = (self.width / 12.0).max(1.0) as u32;
+
+        for tuft in 0..tufts {
+            let x = self.width * (f64::from(tuft) + 0.5) / f64::from(tufts);
+            let blade_height = self.height * if tuft % 2 == 0 { 0.05 } else { 0.035 };
+            Line::new(x, ground, x - 2.0, ground + blade_height * 0.8, Theme::global().paint(Color::Green)).draw(painter);
+            Line::new(x, ground, x, ground + blade_height, Theme::global().paint(Color::Green)).draw(painter);
+            Line::new(x, ground, x + 2.0, ground + blade_height * 0.8, Theme::global().paint(Color::Green)).draw(painter);
+        }
+    }
+}
+
+impl Shape for SceneryShape {
+    fn draw(&self, painter: &mut Painter) {
+        match self.scenery {
+            Scenery::Plain => self.draw_ground(painter, Color::DarkGray),
+            Scenery::Pond => {
+                self.draw_ground(painter, Color::Green);
+                self.draw_pond(painter);
+            },
+            Scenery::WebCorner => {
+                self.draw_ground(painter, Color::DarkGray);
+                self.draw_web_corner(painter);
+            },
+            Scenery::Grass => {
+                self.draw_ground(painter, Color::Green);
+                self.draw_grass(painter);
+            },
+        }
+    }
+}
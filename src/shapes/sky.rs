@@ -0,0 +1,73 @@
+use std::f64::consts::PI;
+use ratatui::style::Color;
+use crate::theme::Theme;
+use ratatui::widgets::canvas::{Circle, Painter, Points, Shape};
+use crate::day_cycle::{self, DayPhase, BEDTIME_HOUR, WAKE_UP_HOUR};
+
+/// Relative positions of the stars that are drawn in the night sky.
+const STARS: [(f64, f64); 8] = [
+    (0.08, 0.92), (0.21, 0.78), (0.34, 0.95), (0.47, 0.84),
+    (0.59, 0.97), (0.71, 0.8), (0.83, 0.9), (0.95, 0.76),
+];
+
+/// ## SkyShape
+/// Draws the sky of the playground for a certain time of the day. During the day a sun moves
+/// from left to right along the sky, at night it is replaced by the moon and some stars.
+pub struct SkyShape {
+    timestamp: i64,
+    width: f64,
+    height: f64,
+}
+
+impl SkyShape {
+    /// Creates a new `SkyShape`.
+    /// <br>
+    /// ## parameters:
+    /// * `timestamp` - The utc time in millis that should be used to determine what the sky looks like.
+    /// * `width` - The width of the canvas the sky is drawn on.
+    /// * `height` - The height of the canvas the sky is drawn on.
+    pub fn new(timestamp: i64, width: f64, height: f64) -> Self {
+        Self {
+            timestamp,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the position of the sun or moon as `(x, y)`. Both rise on the left of the canvas
+    /// and set on the right, peaking in the middle of their part of the day.
+    fn celestial_position(&self, is_night: bool) -> (f64, f64) {
+        let hours = day_cycle::day_progress(self.timestamp) * 24.0;
+        let (rise, set) = (f64::from(WAKE_UP_HOUR), f64::from(BEDTIME_HOUR));
+
+        let progress = if is_night {
+            let hours_since_set = if hours >= set { hours - set } else { hours + 24.0 - set };
+            hours_since_set / (24.0 - set + rise)
+        } else {
+            (hours - rise) / (set - rise)
+        }.clamp(0.0, 1.0);
+
+        let x = self.width * (0.1 + 0.8 * progress);
+        let y = self.height * (0.7 + 0.2 * (progress * PI).sin());
+        (x, y)
+    }
+}
+
+impl Shape for SkyShape {
+    fn draw(&self, painter: &mut Painter) {
+        let is_night = DayPhase::from_timestamp(self.timestamp) == DayPhase::Night;
+        let (x, y) = self.celestial_position(is_night);
+        let radius = self.height * 0.06;
+
+        if is_night {
+            let stars: Vec<(f64, f64)> = STARS.iter()
+                .map(|(x, y)| (x * self.width, y * self.height))
+                .collect();
+            Points { coords: &stars, color: Theme::global().paint(Color::White) }.draw(painter);
+            Circle { x, y, radius, color: Theme::global().paint(Color::Gray) }.draw(painter);
+        } else {
+            Circle { x, y, radius, color: Theme::global().paint(Color::Yellow) }.draw(painter);
+            Circle { x, y, radius: radius / 2.0, color: Theme::global().paint(Color::Yellow) }.draw(painter);
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use ratatui::style::Color;
+use crate::theme::Theme;
+use ratatui::widgets::canvas::{Line, Painter, Points, Shape};
+use crate::weather::Weather;
+
+/// Seed for the particle layout, so particles keep their place in between frames.
+const PARTICLE_SEED: u64 = 0x7E44_A607;
+const RAIN_DROPS: usize = 40;
+const SNOW_FLAKES: usize = 30;
+const WIND_STREAKS: usize = 6;
+
+/// ## WeatherParticles
+/// Draws animated particles for the current weather, like rain drops or snow flakes.
+/// The position of every particle is derived from the time, so each frame moves them a bit further.
+pub struct WeatherParticles {
+    weather: Weather,
+    timestamp: i64,
+    width: f64,
+    height: f64,
+}
+
+/// A single particle, with its relative starting position and speed.
+struct Particle {
+    x: f64,
+    y: f64,
+    speed: f64,
+}
+
+impl WeatherParticles {
+    /// Creates a new `WeatherParticles` shape.
+    /// <br>
+    /// ## parameters:
+    /// * `weather` - The weather to draw the particles for.
+    /// * `timestamp` - The utc time in millis, used to animate the particles.
+    /// * `width` - The width of the canvas the particles are drawn on.
+    /// * `height` - The height of the canvas the particles are drawn on.
+    pub fn new(weather: Weather, timestamp: i64, width: f64, height: f64) -> Self {
+        Self {
+            weather,
+            timestamp,
+            width,
+            height,
+        }
+    }
+
+    fn particles(count: usize) -> Vec<Particle> {
+        let mut rng = StdRng::seed_from_u64(PARTICLE_SEED);
+        (0..count)
+            .map(|_| Particle {
+                x: rng.gen_range(0.0..1.0),
+                y: rng.gen_range(0.0..1.0),
+                speed: rng.gen_range(0.7..1.3),
+            })
+            .collect()
+    }
+
+    /// Returns how far a particle has travelled as a value in range `0.0..1.0`.
+    /// <br>
+    /// ## parameters:
+    /// * `particle` - The particle to move.
+    /// * `start` - The relative starting point of the particle.
+    /// * `cycle_millis` - How long it takes an average particle to travel across the canvas.
+    fn travelled(&self, particle: &Particle, start: f64, cycle_millis: f64) -> f64 {
+        (start + self.timestamp as f64 / cycle_millis * particle.speed).fract()
+    }
+
+    fn draw_rain(&self, painter: &mut Painter) {
+        for drop in Self::particles(RAIN_DROPS) {
+            let x = drop.x * self.width;
+            let y = self.height * (1.0 - self.travelled(&drop, drop.y, 1500.0));
+            Line::new(x, y, x - 1.0, y - 3.0, Theme::global().paint(Color::LightBlue)).draw(painter);
+        }
+    }
+
+    fn draw_snow(&self, painter: &mut Painter) {
+        let flakes: Vec<(f64, f64)> = Self::particles(SNOW_FLAKES).iter()
+            .map(|flake| {
+                let fallen = self.travelled(flake, flake.y, 6000.0);
+                let sway = (fallen * 12.0 + flake.x * 6.0).sin() * 2.0;
+                (flake.x * self.width + sway, self.height * (1.0 - fallen))
+            })
+            .collect();
+
+        Points { coords: &flakes, color: Theme::global().paint(Color::White) }.draw(painter);
+    }
+
+    fn draw_wind(&self, painter: &mut Painter) {
+        for streak in Self::particles(WIND_STREAKS) {
+            let x = self.width * self.travelled(&streak, streak.x, 2500.0);
+            let y = self.height * (0.2 + 0.6 * streak.y);
+            Line::new(x, y, x + 8.0, y, Theme::global().paint(Color::Gray)).draw(painter);
+        }
+    }
+}
+
+impl Shape for WeatherParticles {
+    fn draw(&self, painter: &mut Painter) {
+        match self.weather {
+            Weather::Sunny => (),
+            Weather::Rainy => self.draw_rain(painter),
+            Weather::Snowy => self.draw_snow(painter),
+            Weather::Windy => self.draw_wind(painter),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use crate::food::Food;
+use crate::item::Item;
+use crate::utils::ColorWrapper;
+
+/// Everything that can be bought in the shop, in the order it is displayed.
+pub const SHOP_ITEMS: [ShopItem; 10] = [
+    ShopItem::Food(Food::Soup),
+    ShopItem::Food(Food::Cookie),
+    ShopItem::Food(Food::Burger),
+    ShopItem::Medicine,
+    ShopItem::Item(Item::Ball),
+    ShopItem::Item(Item::Plant),
+    ShopItem::Item(Item::Bed),
+    ShopItem::Paint(ColorWrapper::Cyan),
+    ShopItem::Paint(ColorWrapper::LightMagenta),
+    ShopItem::Paint(ColorWrapper::Yellow),
+];
+
+/// A single product in the shop.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ShopItem {
+    Food(Food),
+    Medicine,
+    Item(Item),
+    /// A cosmetic that gives the pet a new color.
+    Paint(ColorWrapper),
+}
+
+impl ShopItem {
+    pub fn price(&self) -> u32 {
+        match self {
+            ShopItem::Food(food) => food.points() / 5,
+            ShopItem::Medicine => 6,
+            ShopItem::Item(Item::Ball) => 15,
+            ShopItem::Item(Item::Plant) => 20,
+            ShopItem::Item(Item::Bed) => 40,
+            ShopItem::Paint(_) => 25,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            ShopItem::Food(food) => format!("{food:?}"),
+            ShopItem::Medicine => String::from("Medicine"),
+            ShopItem::Item(item) => format!("{item:?}"),
+            ShopItem::Paint(color) => format!("{color:?} paint"),
+        }
+    }
+}
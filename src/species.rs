@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::OnceLock;
+use image::ImageReader;
+use serde::{Deserialize, Serialize};
+use crate::food::{Food, FOODS};
+use crate::friend::GrowthStage;
+use crate::shapes::scenery::Scenery;
+use crate::utils::file_logging::log_to_file;
+use crate::utils::sprite_management::{get_colored_pixels, load_sprite};
+use crate::utils::{ColorWrapper, Pixel};
+
+const SPECIES_DIR: &str = "./species";
+const MANIFEST_FILE: &str = "species.json";
+
+static SPECIES_PACKS: OnceLock<Vec<SpeciesPack>> = OnceLock::new();
+
+/// How a species moves around the playground once it is an adult.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpeciesMovement {
+    /// Hops up and down in the middle of the playground, like an egg.
+    Hop,
+    /// Takes small steps back and forth, like a baby.
+    SmallSteps,
+    /// Bounces through the whole playground.
+    #[default]
+    Bounce,
+}
+
+/// The `species.json` file of a species pack. Every field is optional.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpeciesManifest {
+    /// The name of the species, which is the name of the pack's directory when it is empty.
+    pub name: String,
+    pub movement: SpeciesMovement,
+    /// The food the species eats. Every pet eats soup, and an empty diet means the species eats everything.
+    pub diet: Vec<Food>,
+    /// If the species enjoys the rain, instead of getting sad because of it.
+    pub likes_water: bool,
+    /// The scenery that best suits the natural habitat of the species.
+    pub habitat: Scenery,
+}
+
+impl Default for SpeciesManifest {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            movement: SpeciesMovement::default(),
+            diet: Vec::new(),
+            likes_water: false,
+            habitat: Scenery::Plain,
+        }
+    }
+}
+
+/// ## SpeciesPack
+/// A species the player added by creating a directory in `./species`, with a `species.json` manifest
+/// and an `adult.png` sprite. The directory can also hold an `egg.png`, `baby.png` and `kid.png`,
+/// the default sprites are used for the growth stages without one.
+#[derive(Debug, Clone)]
+pub struct SpeciesPack {
+    id: String,
+    manifest: SpeciesManifest,
+    sprites: HashMap<GrowthStage, Vec<u8>>,
+}
+
+impl SpeciesPack {
+    /// Loads the species pack in the given directory. Sprites can't be larger than the built-in sprites
+    /// of their growth stage, so the pet still fits in the playground.
+    /// <br>
+    /// ## parameters:
+    /// * `dir` - The directory of the pack, its name is used to refer to the pack in save files.
+    pub fn load(dir: &Path) -> std::io::Result<Self> {
+        let id = dir.file_name()
+            .and_then(|name| name.to_str())
+            .map(String::from)
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "Invalid species directory"))?;
+
+        let mut manifest: SpeciesManifest = serde_json::from_str(&fs::read_to_string(dir.join(MANIFEST_FILE))?)?;
+        if manifest.name.is_empty() {
+            manifest.name = id.clone();
+        }
+
+        let mut sprites = HashMap::new();
+        for (stage, file) in [
+            (GrowthStage::Egg, "egg.png"),
+            (GrowthStage::Baby, "baby.png"),
+            (GrowthStage::Kid, "kid.png"),
+            (GrowthStage::Adult, "adult.png"),
+        ] {
+            let path = dir.join(file);
+            if path.exists() {
+                let bytes = fs::read(path)?;
+                get_colored_pixels(&bytes)?;
+
+                let (width, height) = ImageReader::new(Cursor::new(&bytes))
+                    .with_guessed_format()?
+                    .into_dimensions()
+                    .map_err(std::io::Error::other)?;
+                let size = stage.sprite_size();
+                if width > size || height > size {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("{file} is {width}x{height} pixels, but can be at most {size}x{size}"),
+                    ));
+                }
+                sprites.insert(stage, bytes);
+            }
+        }
+
+        if !sprites.contains_key(&GrowthStage::Adult) {
+            return Err(std::io::Error::new(std::io::ErrorKind::NotFound, "Species pack has no adult.png"));
+        }
+
+        Ok(Self { id, manifest, sprites })
+    }
+
+    /// Loads every species pack in the given directory, skipping and logging the packs that can't be loaded.
+    /// <br>
+    /// ## parameters:
+    /// * `dir` - The directory that holds a directory for each species pack.
+    pub fn load_all(dir: &Path) -> Vec<Self> {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Vec::new();
+        };
+
+        let mut packs: Vec<Self> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_dir())
+            .filter_map(|path| match Self::load(&path) {
+                Ok(pack) => Some(pack),
+                Err(error) => {
+                    let _ = log_to_file(&format!("Skipped species pack {}: {error}", path.display()));
+                    None
+                }
+            })
+            .collect();
+
+        packs.sort_by(|a, b| a.id.cmp(&b.id));
+        packs
+    }
+
+    /// Returns the species packs in `./species`, which are loaded the first time they are needed.
+    pub fn installed() -> &'static [SpeciesPack] {
+        SPECIES_PACKS.get_or_init(|| Self::load_all(Path::new(SPECIES_DIR)))
+    }
+
+    /// Returns the installed species pack with the given id, if it is still installed.
+    pub fn find(id: &str) -> Option<&'static SpeciesPack> {
+        Self::installed().iter().find(|pack| pack.id == id)
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn manifest(&self) -> &SpeciesManifest {
+        &self.manifest
+    }
+
+    /// Returns the food the species eats, which always includes soup.
+    pub fn diet(&self) -> Vec<Food> {
+        if self.manifest.diet.is_empty() {
+            return FOODS.to_vec();
+        }
+
+        let mut diet = self.manifest.diet.clone();
+        if !diet.contains(&Food::Soup) {
+            diet.insert(0, Food::Soup);
+        }
+        diet
+    }
+
+    /// Returns the sprite of the species for the given growth stage, if the pack has one.
+    /// <br>
+    /// ## parameters:
+    /// * `stage` - The growth stage to get the sprite for.
+    /// * `color` - The color of the body of the sprite.
+    pub fn pixels(&'static self, stage: GrowthStage, color: ColorWrapper) -> Option<Vec<Pixel>> {
+        let bytes = self.sprites.get(&stage)?;
+        load_sprite(bytes, color.get_ratatui_color()).ok()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::Cursor;
+    use image::{ImageFormat, Rgba, RgbaImage};
+    use crate::food::{Food, FOODS};
+    use crate::friend::{Friend, GrowthStage};
+    use crate::shapes::creatures::CreatureShapes;
+    use crate::shapes::PixelImage;
+    use crate::shapes::scenery::Scenery;
+    use crate::species::{SpeciesMovement, SpeciesPack};
+    use crate::utils::ColorWrapper;
+
+    #[test]
+    fn loads_species_packs() {
+        let dir = std::env::temp_dir().join(format!("termagotchi-species-{}", std::process::id()));
+        let cat = dir.join("cat");
+        fs::create_dir_all(&cat).unwrap();
+        fs::write(
+            cat.join("species.json"),
+            r#"{ "name": "Cat", "movement": "SmallSteps", "diet": ["Burger"], "habitat": "Grass" }"#,
+        ).unwrap();
+
+        let mut sprite = Vec::new();
+        RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 255]))
+            .write_to(&mut Cursor::new(&mut sprite), ImageFormat::Png)
+            .unwrap();
+        fs::write(cat.join("adult.png"), sprite).unwrap();
+
+        let packs = SpeciesPack::load_all(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(packs.len(), 1);
+        // Sprites are cached by the address of their image, so they have to live as long as the game.
+        let pack: &'static SpeciesPack = &packs.leak()[0];
+        assert_eq!(pack.id(), "cat");
+        assert_eq!(pack.manifest().name, "Cat");
+        assert_eq!(pack.manifest().movement, SpeciesMovement::SmallSteps);
+        assert_eq!(pack.manifest().habitat, Scenery::Grass);
+        assert_eq!(pack.diet(), vec![Food::Soup, Food::Burger]);
+        assert_eq!(pack.pixels(GrowthStage::Adult, ColorWrapper::Red).unwrap().len(), 4);
+        assert!(pack.pixels(GrowthStage::Egg, ColorWrapper::Red).is_none());
+    }
+
+    #[test]
+    fn large_sprites_are_rejected() {
+        let dir = std::env::temp_dir().join(format!("termagotchi-large-species-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("species.json"), "{}").unwrap();
+
+        let mut sprite = Vec::new();
+        RgbaImage::from_pixel(11, 10, Rgba([0, 0, 0, 255]))
+            .write_to(&mut Cursor::new(&mut sprite), ImageFormat::Png)
+            .unwrap();
+        fs::write(dir.join("adult.png"), &sprite).unwrap();
+        assert!(SpeciesPack::load(&dir).is_ok());
+
+        fs::write(dir.join("egg.png"), &sprite).unwrap();
+        let error = SpeciesPack::load(&dir).unwrap_err();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn missing_packs_fall_back() {
+        let shape: CreatureShapes = serde_json::from_str(r#"{"Custom":["not-installed","Red"]}"#).unwrap();
+        assert_eq!(shape.species(), "not-installed");
+        assert_eq!(shape.habitat(), Scenery::Plain);
+        assert_eq!(shape.diet(), FOODS.to_vec());
+        assert_eq!(shape.movement(), SpeciesMovement::Bounce);
+        assert!(!shape.pixels().is_empty());
+
+        let friend = Friend::new("ghost", shape);
+        assert_ne!(friend.get_pixel_vector().get_dimensions(), (0, 0));
+    }
+}
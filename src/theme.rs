@@ -0,0 +1,205 @@
+use std::sync::OnceLock;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use crate::day_cycle::DayPhase;
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// The colors the game is drawn with. Every color the game uses goes through the theme,
+/// so a theme can replace the colors of the sprites, the scenery, the sky and the stats.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    /// Made for terminals with a dark background.
+    #[default]
+    Dark,
+    /// Made for terminals with a light background.
+    Light,
+    /// Bright colors on a black background.
+    HighContrast,
+    /// Colors that people with red-green color blindness can tell apart.
+    Colorblind,
+    /// No colors at all, only the default colors of the terminal.
+    Monochrome,
+}
+
+impl Theme {
+    /// Returns the theme to use, which is always the monochrome theme when the `NO_COLOR`
+    /// environment variable is set (see https://no-color.org).
+    /// <br>
+    /// ## parameters:
+    /// * `theme` - The theme the player picked in their config.
+    pub fn from_config(theme: Theme) -> Theme {
+        match std::env::var_os("NO_COLOR") {
+            Some(value) if !value.is_empty() => Theme::Monochrome,
+            _ => theme,
+        }
+    }
+
+    /// Sets the theme used by the whole game. Only the first call has an effect.
+    pub fn set_global(theme: Theme) {
+        let _ = THEME.set(theme);
+    }
+
+    /// Returns the theme used by the whole game, which is the dark theme when none was set.
+    pub fn global() -> Theme {
+        *THEME.get_or_init(Theme::default)
+    }
+
+    /// Returns the color to draw with in place of one of the standard colors.
+    /// <br>
+    /// ## parameters:
+    /// * `color` - The color the dark theme would use.
+    pub fn paint(&self, color: Color) -> Color {
+        match self {
+            Theme::Dark => color,
+            Theme::Light => match color {
+                Color::White => Color::Black,
+                Color::Gray => Color::DarkGray,
+                Color::Yellow => Color::Rgb(170, 120, 0),
+                Color::LightGreen => Color::Green,
+                Color::LightBlue => Color::Blue,
+                Color::Cyan => Color::Rgb(0, 120, 140),
+                Color::LightMagenta => Color::Magenta,
+                Color::LightRed => Color::Red,
+                color => color,
+            },
+            Theme::HighContrast => match color {
+                Color::Red => Color::LightRed,
+                Color::Green => Color::LightGreen,
+                Color::Blue => Color::LightBlue,
+                Color::Cyan => Color::LightCyan,
+                Color::Magenta => Color::LightMagenta,
+                Color::Yellow => Color::LightYellow,
+                Color::Gray => Color::White,
+                Color::DarkGray => Color::Gray,
+                color => color,
+            },
+            // Uses the palette by Okabe and Ito, where no two colors only differ in red and green.
+            Theme::Colorblind => match color {
+                Color::Red | Color::LightRed => Color::Rgb(213, 94, 0),
+                Color::Green | Color::LightGreen => Color::Rgb(0, 158, 115),
+                Color::Blue => Color::Rgb(0, 114, 178),
+                Color::LightBlue | Color::Cyan => Color::Rgb(86, 180, 233),
+                Color::Magenta | Color::LightMagenta => Color::Rgb(204, 121, 167),
+                Color::Yellow => Color::Rgb(240, 228, 66),
+                color => color,
+            },
+            Theme::Monochrome => Color::Reset,
+        }
+    }
+
+    /// Returns the color of a stat, going from bad to good as the value goes up.
+    /// <br>
+    /// ## parameters:
+    /// * `value` - The value of the stat, from 0 to 100.
+    pub fn stat_color(&self, value: u32) -> Color {
+        let value = value.min(100);
+        match self {
+            Theme::Dark | Theme::Light => {
+                let green = (value * 2) as u8;
+                Color::Rgb(200 - green, green, 0)
+            }
+            Theme::HighContrast => match value {
+                0..30 => Color::LightRed,
+                30..60 => Color::LightYellow,
+                _ => Color::LightGreen,
+            },
+            // Goes from orange to blue instead of from red to green.
+            Theme::Colorblind => Color::Rgb(
+                blend(230, 0, value),
+                blend(159, 114, value),
+                blend(0, 178, value),
+            ),
+            Theme::Monochrome => Color::Reset,
+        }
+    }
+
+    /// Returns the background color of the playground while the lights are on.
+    /// <br>
+    /// ## parameters:
+    /// * `phase` - The current phase of the day.
+    pub fn sky_color(&self, phase: DayPhase) -> Color {
+        match self {
+            Theme::Dark | Theme::Colorblind => phase.sky_color(),
+            Theme::Light => match phase {
+                DayPhase::Morning => Color::Rgb(190, 215, 240),
+                DayPhase::Afternoon => Color::Rgb(160, 205, 245),
+                DayPhase::Evening => Color::Rgb(235, 195, 180),
+                DayPhase::Night => Color::Rgb(150, 150, 175),
+            },
+            Theme::HighContrast => Color::Black,
+            Theme::Monochrome => Color::Reset,
+        }
+    }
+
+    /// Returns the background color of the playground while the lights are off.
+    pub fn lights_off_color(&self) -> Color {
+        match self {
+            Theme::Light => Color::Rgb(150, 150, 175),
+            Theme::Monochrome => Color::Reset,
+            _ => Color::Black,
+        }
+    }
+
+    /// Returns the color to draw something with in the dark, which fades into the background
+    /// while the lights are off. Only rgb colors can fade, other colors turn dark gray.
+    /// <br>
+    /// ## parameters:
+    /// * `color` - The color the thing has while the lights are on.
+    pub fn dimmed(&self, color: Color) -> Color {
+        let [to_red, to_green, to_blue] = match self.lights_off_color() {
+            Color::Rgb(red, green, blue) => [red, green, blue],
+            _ => [0, 0, 0],
+        };
+        match (self, color) {
+            (Theme::Monochrome, _) => Color::Reset,
+            (_, Color::Rgb(red, green, blue)) => Color::Rgb(
+                blend(red, to_red, 60),
+                blend(green, to_green, 60),
+                blend(blue, to_blue, 60),
+            ),
+            _ => Color::DarkGray,
+        }
+    }
+
+    /// Returns the color of the "zz" above a sleeping pet.
+    pub fn sleep_color(&self) -> Color {
+        self.paint(Color::White)
+    }
+}
+
+/// Returns a value between `from` and `to`, which is `from` at 0 percent and `to` at 100 percent.
+fn blend(from: u8, to: u8, percent: u32) -> u8 {
+    let (from, to, percent) = (from as i32, to as i32, percent as i32);
+    (from + (to - from) * percent / 100) as u8
+}
+
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+    use crate::day_cycle::DayPhase;
+    use crate::theme::Theme;
+
+    #[test]
+    fn themes_replace_colors() {
+        assert_eq!(Theme::Dark.paint(Color::LightGreen), Color::LightGreen);
+        assert_eq!(Theme::Light.paint(Color::White), Color::Black);
+        assert_eq!(Theme::HighContrast.paint(Color::DarkGray), Color::Gray);
+        assert_eq!(Theme::Monochrome.paint(Color::Red), Color::Reset);
+        assert_eq!(Theme::Monochrome.sky_color(DayPhase::Morning), Color::Reset);
+
+        assert_eq!(Theme::Dark.stat_color(100), Color::Rgb(0, 200, 0));
+        assert_eq!(Theme::Colorblind.stat_color(0), Color::Rgb(230, 159, 0));
+        assert_eq!(Theme::Colorblind.stat_color(100), Color::Rgb(0, 114, 178));
+        assert_eq!(Theme::HighContrast.stat_color(10), Color::LightRed);
+
+        assert_eq!(Theme::Dark.dimmed(Color::Rgb(200, 100, 50)), Color::Rgb(80, 40, 20));
+        assert_eq!(Theme::Dark.dimmed(Color::Red), Color::DarkGray);
+        assert_eq!(Theme::Monochrome.dimmed(Color::Red), Color::Reset);
+
+        let theme: Theme = serde_json::from_str("\"high_contrast\"").unwrap();
+        assert_eq!(theme, Theme::HighContrast);
+    }
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use crate::friend::{Friend, GrowthStage};
+use crate::history::LifetimeTotals;
+use crate::keymap::{KeyAction, Keymap};
+
+/// The steps of the tutorial that walks a new player through hatching, feeding and
+/// putting their first pet to bed. Save files from before the tutorial was added skip it.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Tutorial {
+    /// Waiting for the egg to hatch.
+    Hatch,
+    /// Waiting for the player to feed their pet.
+    Feed,
+    /// Waiting for the player to put their pet to bed.
+    Sleep,
+    /// Every step is done, waiting for the player to close the tutorial.
+    Done,
+    #[default]
+    Finished,
+}
+
+impl Tutorial {
+    /// Moves on to the next step once the player did what the current step asked for.
+    /// <br>
+    /// ## parameters:
+    /// * `friend` - The friend the player is taking care of.
+    /// * `totals` - How often the player took care of the friend.
+    pub fn progress(&mut self, friend: &Friend, totals: LifetimeTotals) {
+        let next = match self {
+            Tutorial::Hatch if friend.growth_stage() != GrowthStage::Egg => Tutorial::Feed,
+            Tutorial::Feed if totals.meals > 0 => Tutorial::Sleep,
+            Tutorial::Sleep if friend.is_asleep() => Tutorial::Done,
+            _ => return,
+        };
+        *self = next;
+    }
+
+    pub fn is_active(&self) -> bool {
+        *self != Tutorial::Finished
+    }
+
+    /// Returns the number of the current step, and the number of steps there are.
+    pub fn step(&self) -> (u8, u8) {
+        let step = match self {
+            Tutorial::Hatch => 1,
+            Tutorial::Feed => 2,
+            Tutorial::Sleep => 3,
+            Tutorial::Done | Tutorial::Finished => 4,
+        };
+        (step, 4)
+    }
+
+    /// Returns what the player should do for the current step, using the keys from their keymap.
+    /// <br>
+    /// ## parameters:
+    /// * `friend` - The friend the player is taking care of.
+    /// * `keymap` - The keymap the player is using.
+    pub fn instructions(&self, friend: &Friend, keymap: &Keymap) -> String {
+        let name = friend.name();
+        match self {
+            Tutorial::Hatch => format!(
+                "{name} is still an egg, it will hatch after about {} minutes. Eggs don't need anything, \
+                so press '{}' to read how your pet works while you wait.",
+                GrowthStage::Egg.grows_after_millis().unwrap_or_default() / 1000 / 60,
+                keymap.keys(KeyAction::Help),
+            ),
+            Tutorial::Feed => format!(
+                "{name} has hatched, and it's hungry! Select 'Eat' in the actions on the right and press '{}', \
+                or press '{}' to feed it right away.",
+                keymap.keys(KeyAction::Select), keymap.keys(KeyAction::Eat),
+            ),
+            Tutorial::Sleep => format!(
+                "Babies need a lot of rest. Put {name} to bed with 'Sleep', or press '{}'. \
+                Switching off the 'Lights' helps it sleep even better.",
+                keymap.keys(KeyAction::Sleep),
+            ),
+            Tutorial::Done | Tutorial::Finished => format!(
+                "Sweet dreams! {name} wakes up on its own, but you can also wake it up with 'Sleep'. \
+                Keep checking on it regularly, and press '{}' to close the tutorial.",
+                keymap.keys(KeyAction::Back),
+            ),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::environment::Environment;
+    use crate::friend::Friend;
+    use crate::history::LifetimeTotals;
+    use crate::shapes::creatures::CreatureShapes;
+    use crate::tutorial::Tutorial;
+    use crate::utils::ColorWrapper;
+
+    #[test]
+    fn tutorial_follows_the_pet() {
+        let mut friend = Friend::new("duck", CreatureShapes::Duck(ColorWrapper::Red));
+        let environment = Environment { lights_on: true, weather_seed: 0, has_bed: false };
+        let mut tutorial = Tutorial::Hatch;
+
+        tutorial.progress(&friend, LifetimeTotals::default());
+        assert_eq!(tutorial, Tutorial::Hatch);
+
+        friend.update_state(friend.time_created() + 1000 * 60 * 6, &environment);
+        tutorial.progress(&friend, LifetimeTotals::default());
+        assert_eq!(tutorial, Tutorial::Feed);
+
+        let fed = LifetimeTotals { meals: 1, ..LifetimeTotals::default() };
+        tutorial.progress(&friend, fed);
+        assert_eq!(tutorial, Tutorial::Sleep);
+
+        friend.toggle_sleep(friend.time_created() + 1000 * 60 * 7);
+        tutorial.progress(&friend, fed);
+        assert_eq!(tutorial, Tutorial::Done);
+        assert!(tutorial.is_active());
+    }
+}
@@ -1,6 +1,7 @@
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 use rand::prelude::*;
+use crate::theme::Theme;
 
 
 const NUM_COLORS: u32 = 9;
@@ -19,8 +20,9 @@ pub enum ColorWrapper {
 }
 
 impl ColorWrapper {
+    /// Returns the color to draw with, using the colors of the current theme.
     pub fn get_ratatui_color(&self) -> Color {
-        match self {
+        let color = match self {
             ColorWrapper::Red => Color::Red,
             ColorWrapper::Green => Color::LightGreen,
             ColorWrapper::Blue => Color::LightBlue,
@@ -31,7 +33,8 @@ impl ColorWrapper {
             
             ColorWrapper::White => Color::White,
             ColorWrapper::Black => Color::Black,
-        }
+        };
+        Theme::global().paint(color)
     }
     
     /// Generates a random variant of `ColorWrapper`, excluding Black and White.
@@ -1,9 +1,20 @@
-use std::error::Error;
+use std::collections::HashMap;
 use image::load_from_memory;
 use image::imageops::flip_vertical;
+use std::sync::{Arc, Mutex, OnceLock};
 use ratatui::prelude::Color;
+use crate::theme::Theme;
 use crate::utils::Pixel;
 
+/// Cached pixels of sprites, which are shared instead of decoded again.
+type SpriteCache<K> = OnceLock<Mutex<HashMap<K, Arc<Vec<Pixel>>>>>;
+
+/// The decoded pixels of every sprite that was loaded, by the address of its image bytes.
+static SPRITE_CACHE: SpriteCache<usize> = OnceLock::new();
+/// The pixels of every sprite that was loaded with the colors they are drawn with,
+/// by the address of its image bytes and the color of its body.
+static COLORED_SPRITE_CACHE: SpriteCache<(usize, Color)> = OnceLock::new();
+
 #[macro_export]
 macro_rules! load_embedded_sprite {
     ($sprite_path:expr, $color:expr) => {
@@ -15,44 +26,111 @@ macro_rules! load_embedded_sprite {
 }
 
 
-pub fn load_sprite(image_bytes: &[u8], color: Color) -> std::io::Result<Vec<Pixel>> {
-    let mut pixels = Vec::new();
-    let black_pixel_cords = get_black_pixel_coordinates(image_bytes)
-        .map_err(|_| std::io::Error::new(std::io::ErrorKind::NotFound, "Sprite image not found"))?;
+/// Loads the pixels of a sprite. Pure black pixels are the body of the sprite and get the given color,
+/// white and transparent pixels are the background and are left out, and every other pixel keeps its own
+/// color, so sprites can have eyes, shading and accents.
+/// Every image is only decoded and colored the first time it is loaded in a color, after that its pixels
+/// come from a cache. The cache knows the images by their address, so they have to live as long as the game.
+/// <br>
+/// ## parameters:
+/// * `image_bytes` - A slice of the bytes for the image that should get used.
+/// * `color` - The color of the body of the sprite.
+pub fn load_sprite(image_bytes: &'static [u8], color: Color) -> std::io::Result<Vec<Pixel>> {
+    let key = (image_bytes.as_ptr() as usize, color);
+    let cache = COLORED_SPRITE_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(pixels) = cache.lock().unwrap().get(&key) {
+        return Ok(pixels.to_vec());
+    }
+
+    let colored_pixels = get_cached_pixels(image_bytes)?;
+    let pixels: Arc<Vec<Pixel>> = Arc::new(colored_pixels.iter()
+        .map(|pixel| Pixel {
+            color: match pixel.color {
+                Color::Rgb(0, 0, 0) => color,
+                Color::Rgb(red, green, blue) => Theme::global().paint(sprite_color([red, green, blue], supports_truecolor())),
+                own_color => own_color,
+            },
+            ..*pixel
+        })
+        .collect());
+    cache.lock().unwrap().insert(key, Arc::clone(&pixels));
 
-    for cords in black_pixel_cords {
-        pixels.push(Pixel {
-            x: cords.0,
-            y: cords.1,
-            color,
-        });
+    Ok(pixels.to_vec())
+}
+
+/// Returns the decoded pixels of the image from the cache, and decodes and caches them when they aren't cached yet.
+/// <br>
+/// ## parameters:
+/// * `image_bytes` - A slice of the bytes for the image that should get used.
+fn get_cached_pixels(image_bytes: &'static [u8]) -> std::io::Result<Arc<Vec<Pixel>>> {
+    let key = image_bytes.as_ptr() as usize;
+
+    let cache = SPRITE_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(pixels) = cache.lock().unwrap().get(&key) {
+        return Ok(Arc::clone(pixels));
     }
 
+    let pixels = Arc::new(get_colored_pixels(image_bytes)?);
+    cache.lock().unwrap().insert(key, Arc::clone(&pixels));
     Ok(pixels)
 }
 
-/// Returns the coordinates of each black pixel in a vector. This also flips the image vertically since
-/// `ratatui`'s coordinate system goes from left to right, **BOTTOM** to **TOP**.
+/// Returns every pixel that isn't part of the background, with its rgb color. This also flips
+/// the image vertically since `ratatui`'s coordinate system goes from left to right, **BOTTOM** to **TOP**.
+/// This decodes the image every time, without the cache, so images that don't live as long as the game can be checked.
 /// <br>
 /// ## parameters:
 /// * `image_bytes` - A slice of the bytes for the image that should get used. 
-fn get_black_pixel_coordinates(image_bytes: &[u8]) -> Result<Vec<(u32, u32)>, Box<dyn Error>> {
-    let image = load_from_memory(image_bytes).unwrap().to_luma8();
+pub fn get_colored_pixels(image_bytes: &[u8]) -> std::io::Result<Vec<Pixel>> {
+    let image = load_from_memory(image_bytes)
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::NotFound, "Sprite image not found"))?
+        .to_rgba8();
     let dimensions = image.dimensions();
-    let mut buffer: Vec<(u32, u32)> = Vec::new();
+    let mut buffer = Vec::new();
     
     // flip the image over the x-axis, since ratatui coordinates start at the BOTTOM left, not TOP left.
     let flipped_image = flip_vertical(&image);
     for (pixel_index, pixel) in flipped_image.pixels().enumerate() {
-        if pixel.0[0] == 0 {
-            let cords = get_pixel_coordinates(pixel_index, dimensions);
-            buffer.push(cords);
+        let [red, green, blue, alpha] = pixel.0;
+        if alpha < 128 || [red, green, blue] == [255, 255, 255] {
+            continue;
         }
+
+        let (x, y) = get_pixel_coordinates(pixel_index, dimensions);
+        buffer.push(Pixel { x, y, color: Color::Rgb(red, green, blue) });
     }
 
     Ok(buffer)
 }
 
+/// Returns true when the terminal says it can show any rgb color, which most terminals do through `COLORTERM`.
+fn supports_truecolor() -> bool {
+    static TRUECOLOR: OnceLock<bool> = OnceLock::new();
+    *TRUECOLOR.get_or_init(|| {
+        std::env::var("COLORTERM").is_ok_and(|value| value == "truecolor" || value == "24bit")
+    })
+}
+
+/// Returns the color to draw a pixel of a sprite with. Terminals without truecolor get the closest
+/// color of the 256 color palette instead.
+/// <br>
+/// ## parameters:
+/// * `rgb` - The color of the pixel in the sprite.
+/// * `truecolor` - If the terminal can show any rgb color.
+fn sprite_color(rgb: [u8; 3], truecolor: bool) -> Color {
+    if truecolor {
+        return Color::Rgb(rgb[0], rgb[1], rgb[2]);
+    }
+
+    // The palette has a 6x6x6 color cube starting at 16, and 24 shades of gray starting at 232.
+    let [red, green, blue] = rgb.map(|value| (u16::from(value) * 5 + 127) / 255);
+    if rgb[0] == rgb[1] && rgb[1] == rgb[2] && rgb[0] > 3 && rgb[0] < 243 {
+        let shade = (u16::from(rgb[0]) - 3) / 10;
+        return Color::Indexed(232 + shade.min(23) as u8);
+    }
+    Color::Indexed((16 + 36 * red + 6 * green + blue) as u8)
+}
+
 fn get_pixel_coordinates(pixel_index: usize, img_dimensions: (u32, u32)) -> (u32, u32) {
     let pixel_index = pixel_index as u32;
     let y_cord = pixel_index / img_dimensions.0;
@@ -65,7 +143,10 @@ fn get_pixel_coordinates(pixel_index: usize, img_dimensions: (u32, u32)) -> (u32
 
 #[cfg(test)]
 mod test {
-    use super::get_pixel_coordinates;
+    use std::io::Cursor;
+    use image::{ImageFormat, Rgba, RgbaImage};
+    use ratatui::prelude::Color;
+    use super::{get_pixel_coordinates, load_sprite, sprite_color};
 
     #[test]
     fn get_pixel_coordinates_test() {
@@ -83,4 +164,25 @@ mod test {
         assert_eq!(result_c, (1, 0));
         assert_eq!(result_width_1, (0, 7));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn sprites_keep_their_colors() {
+        let image = RgbaImage::from_fn(4, 1, |x, _| match x {
+            0 => Rgba([0, 0, 0, 255]),
+            1 => Rgba([255, 0, 0, 255]),
+            2 => Rgba([255, 255, 255, 255]),
+            _ => Rgba([0, 0, 0, 0]),
+        });
+        let mut bytes = Vec::new();
+        image.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png).unwrap();
+
+        let pixels = load_sprite(bytes.leak(), Color::Green).unwrap();
+        assert_eq!(pixels.len(), 2);
+        assert_eq!(pixels[0].color, Color::Green);
+        assert_eq!(pixels[1].x, 1);
+
+        assert_eq!(sprite_color([255, 0, 0], true), Color::Rgb(255, 0, 0));
+        assert_eq!(sprite_color([255, 0, 0], false), Color::Indexed(196));
+        assert_eq!(sprite_color([128, 128, 128], false), Color::Indexed(244));
+    }
+}
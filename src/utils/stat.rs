@@ -39,21 +39,6 @@ impl Stat {
         }
     }
 
-    /// Sets the Stat to the provided value <br>
-    /// returns [std::io::ErrorKind::InvalidInput] when value does not fit range: <br>
-    /// _0 <= value <= 100_
-    pub fn set(&mut self, value: u32) -> Result<(), std::io::Error> {
-        if value <= 100 {
-            self.0 = value;
-            return Ok(());
-        }
-
-        Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidInput,
-            "value not between 0 and 100",
-        ))
-    }
-    
     pub fn is_max(&self) -> bool {
         self.0 == 100 
     }
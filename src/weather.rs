@@ -0,0 +1,107 @@
+use chrono::{Datelike, Local, TimeZone};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::day_cycle::DayPhase;
+
+/// How long a single type of weather lasts before the weather changes again.
+const WEATHER_PERIOD_MILLIS: i64 = 1000 * 60 * 60 * 3; // 3 hours
+
+/// The weather in the pet's playground. The weather is simulated offline and is fully determined
+/// by a seed and the time, so it is the same every time it is calculated for a certain moment.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Weather {
+    Sunny,
+    Rainy,
+    Snowy,
+    Windy,
+}
+
+impl Weather {
+    /// Returns the weather at the given moment in time.
+    /// <br>
+    /// ## parameters:
+    /// * `timestamp_millis` - The utc time in millis to get the weather for.
+    /// * `seed` - The seed of the weather generator, each save file has its own weather.
+    pub fn at(timestamp_millis: i64, seed: u64) -> Self {
+        let period = timestamp_millis.div_euclid(WEATHER_PERIOD_MILLIS) as u64;
+        let mut rng = StdRng::seed_from_u64(seed ^ period.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+
+        let roll = rng.gen_range(0..100);
+        if is_winter(timestamp_millis) {
+            match roll {
+                0..=24 => Weather::Sunny,
+                25..=44 => Weather::Rainy,
+                45..=79 => Weather::Snowy,
+                _ => Weather::Windy,
+            }
+        } else {
+            match roll {
+                0..=49 => Weather::Sunny,
+                50..=74 => Weather::Rainy,
+                _ => Weather::Windy,
+            }
+        }
+    }
+
+    /// Returns true when it is hot outside, which is on sunny afternoons outside of winter.
+    /// <br>
+    /// ## parameters:
+    /// * `timestamp_millis` - The utc time in millis to check the temperature for.
+    /// * `seed` - The seed of the weather generator.
+    pub fn is_hot(timestamp_millis: i64, seed: u64) -> bool {
+        Self::at(timestamp_millis, seed) == Weather::Sunny
+            && DayPhase::from_timestamp(timestamp_millis) == DayPhase::Afternoon
+            && !is_winter(timestamp_millis)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weather::Sunny => "Sunny",
+            Weather::Rainy => "Rainy",
+            Weather::Snowy => "Snowy",
+            Weather::Windy => "Windy",
+        }
+    }
+}
+
+fn is_winter(timestamp_millis: i64) -> bool {
+    Local.timestamp_millis_opt(timestamp_millis)
+        .earliest()
+        .map(|time| matches!(time.month(), 12 | 1 | 2))
+        .unwrap_or(false)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Weather, WEATHER_PERIOD_MILLIS};
+
+    #[test]
+    fn weather_is_deterministic() {
+        let start = 1_717_200_000_000;
+        let weather: Vec<Weather> = (0..8)
+            .map(|period| Weather::at(start + period * WEATHER_PERIOD_MILLIS, 42))
+            .collect();
+        // Known weather for a fixed seed, so changes to the generator that change the weather of existing save files are noticed.
+        assert_eq!(weather, vec![
+            Weather::Windy, Weather::Windy, Weather::Sunny, Weather::Sunny,
+            Weather::Rainy, Weather::Sunny, Weather::Sunny, Weather::Windy,
+        ]);
+
+        for period in 0..50 {
+            let timestamp = start + period * WEATHER_PERIOD_MILLIS;
+            assert_eq!(Weather::at(timestamp, 42), Weather::at(timestamp + WEATHER_PERIOD_MILLIS / 2, 42));
+        }
+    }
+
+    #[test]
+    fn weather_changes_over_time() {
+        let start = 1_717_200_000_000;
+        let first = Weather::at(start, 7);
+        let changes = (1..50)
+            .map(|period| Weather::at(start + period * WEATHER_PERIOD_MILLIS, 7))
+            .any(|weather| weather != first);
+
+        assert!(changes);
+    }
+}
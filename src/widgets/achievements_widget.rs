@@ -0,0 +1,46 @@
+use chrono::{Local, TimeZone};
+use ratatui::prelude::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListItem};
+use crate::achievements::ACHIEVEMENTS;
+use crate::game_state::GameState;
+use crate::keymap::{KeyAction, Keymap};
+use crate::theme::Theme;
+
+/// Returns a list of all achievements, showing which ones are unlocked and when they were unlocked.
+/// The descriptions of locked secret achievements are hidden.
+/// <br>
+/// ## parameters:
+/// * `game_state` - The game state to get the unlocked achievements from.
+pub fn achievements_list(game_state: &GameState) -> List<'static> {
+    let achievements = game_state.achievements();
+
+    let items: Vec<ListItem> = ACHIEVEMENTS.iter()
+        .map(|achievement| {
+            let unlocked_at = achievements.unlocked_at(*achievement)
+                .and_then(|millis| Local.timestamp_millis_opt(millis).earliest());
+
+            let (status, style) = match unlocked_at {
+                Some(time) => (time.format("%Y-%m-%d").to_string(), Style::new().fg(Theme::global().paint(Color::Yellow))),
+                None => (String::from("locked"), Style::new().fg(Theme::global().paint(Color::DarkGray))),
+            };
+            let description = if achievement.is_secret() && unlocked_at.is_none() {
+                "???"
+            } else {
+                achievement.description()
+            };
+
+            ListItem::new(vec![
+                Line::from(format!("{:<30}{status:>12}", achievement.name())),
+                Line::from(format!("  {description}")),
+            ])
+                .style(style)
+        })
+        .collect();
+
+    let title = format!(" Achievements ({}/{}) ", achievements.unlocked_count(), ACHIEVEMENTS.len());
+    List::new(items)
+        .block(Block::bordered()
+            .title(Line::from(title).centered())
+            .title_bottom(Line::from(format!(" Press '{}' to go back ", Keymap::global().keys(KeyAction::Back))).centered()))
+}
@@ -1,18 +1,112 @@
 use ratatui::prelude::{Modifier, Style};
 use ratatui::text::Line;
-use ratatui::widgets::{Block, List};
+use ratatui::widgets::{Block, List, ListState, Paragraph, Wrap};
+use crate::inventory::Inventory;
+use crate::keymap::{KeyAction, Keymap};
 
 
-pub const ITEMS: [&str; 4] = [
+pub const ITEMS: [&str; 7] = [
     "Eat", 
     "Play", 
     "Sleep",
     "Medicine",
+    "Lights",
+    "Scenery",
+    "Shop",
 ];
 
-pub fn actions_widget() -> List<'static> {
-    List::new(ITEMS)
-        .block(Block::bordered().title(Line::from(" Actions ").centered()))
+/// Returns the list of actions, showing how many of the supplies each action uses are left.
+/// <br>
+/// ## parameters:
+/// * `inventory` - The inventory of the player, used to show the supplies and coins that are left.
+/// * `read_only` - Whether the actions are disabled, because the game is already running somewhere else.
+pub fn actions_widget(inventory: &Inventory, read_only: bool) -> List<'static> {
+    let items = ITEMS.map(|action| match action {
+        "Eat" => format!("Eat ({})", inventory.food_count()),
+        "Medicine" => format!("Medicine ({})", inventory.medicine()),
+        _ => String::from(action),
+    });
+
+    let title = if read_only { " Actions (read-only) " } else { " Actions " };
+    List::new(items)
+        .block(Block::bordered()
+            .title(Line::from(title).centered())
+            .title_bottom(Line::from(format!(" Coins: {} ", inventory.coins())).centered()))
         .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
         .repeat_highlight_symbol(true)
-}
\ No newline at end of file
+}
+
+/// Selects the previous action in the list, or the first one when nothing is selected yet.
+/// Unlike `ListState::select_previous` the selection always stays within the list, even before the list is drawn.
+/// <br>
+/// ## parameters:
+/// * `state` - The state of the actions list.
+pub fn select_previous(state: &mut ListState) {
+    let index = state.selected().map_or(0, |index| index.saturating_sub(1).min(ITEMS.len() - 1));
+    state.select(Some(index));
+}
+
+/// Selects the next action in the list, or the first one when nothing is selected yet.
+/// Unlike `ListState::select_next` the selection always stays within the list, even before the list is drawn.
+/// <br>
+/// ## parameters:
+/// * `state` - The state of the actions list.
+pub fn select_next(state: &mut ListState) {
+    let index = state.selected().map_or(0, |index| (index + 1).min(ITEMS.len() - 1));
+    state.select(Some(index));
+}
+
+/// Returns the selected action, if there is one.
+/// <br>
+/// ## parameters:
+/// * `state` - The state of the actions list.
+pub fn selected_action(state: &ListState) -> Option<&'static str> {
+    state.selected().and_then(|index| ITEMS.get(index).copied())
+}
+
+/// Returns a short line with the hotkey of every action, used instead of the actions list when there is little room.
+/// <br>
+/// ## parameters:
+/// * `keymap` - The keymap the player is using.
+/// * `read_only` - Whether the actions are disabled, because the game is already running somewhere else.
+pub fn actions_hint(keymap: &Keymap, read_only: bool) -> Paragraph<'static> {
+    let mut hints: Vec<String> = [KeyAction::Eat, KeyAction::Play, KeyAction::Sleep, KeyAction::Medicine, KeyAction::Help]
+        .iter()
+        .filter_map(|action| {
+            let name = action.action_item().unwrap_or("Help");
+            keymap.first_key(*action).map(|key| format!("{key}:{name}"))
+        })
+        .collect();
+    if read_only {
+        hints.insert(0, String::from("(read-only)"));
+    }
+
+    Paragraph::new(hints.join(" "))
+        .centered()
+        .wrap(Wrap { trim: true })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::ListState;
+    use crate::widgets::actions_widget::{select_next, select_previous, selected_action, ITEMS};
+
+    #[test]
+    fn selection_stays_within_the_list() {
+        let mut state = ListState::default();
+        select_previous(&mut state);
+        assert_eq!(selected_action(&state), Some(ITEMS[0]));
+
+        for _ in 0..ITEMS.len() + 3 {
+            select_next(&mut state);
+        }
+        assert_eq!(selected_action(&state), Some(ITEMS[ITEMS.len() - 1]));
+
+        select_previous(&mut state);
+        assert_eq!(selected_action(&state), Some(ITEMS[ITEMS.len() - 2]));
+
+        state.select(Some(usize::MAX));
+        assert_eq!(selected_action(&state), None);
+    }
+}
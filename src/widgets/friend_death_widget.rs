@@ -1,9 +1,8 @@
 use ratatui::layout::Alignment;
 use ratatui::prelude::{Line, Stylize, Widget};
-use ratatui::symbols::Marker;
 use ratatui::widgets::{Block, Paragraph, Wrap};
-use ratatui::widgets::canvas::Canvas;
 use crate::load_embedded_sprite;
+use crate::renderer::Renderer;
 use crate::shapes::PixelVectorShape;
 use crate::utils::ColorWrapper;
 use crate::utils::sprite_management::load_sprite;
@@ -13,14 +12,11 @@ pub fn death_canvas() -> impl Widget + 'static {
     let death_message = PixelVectorShape::new(death_message)
         .translate(10, 0);
     
-    Canvas::default()
-        .block(Block::bordered())
-        .marker(Marker::Braille)
-        .x_bounds([0.0, 170.0])
-        .y_bounds([0.0, 100.0])
-        .paint(move |ctx| {
+    Renderer::global()
+        .canvas([0.0, 170.0], [0.0, 100.0], move |ctx| {
             ctx.draw(&death_message);
         })
+        .block(Block::bordered())
 }
 
 pub fn name_input(input: &str) -> impl Widget + '_ {
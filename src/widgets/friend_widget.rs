@@ -1,25 +1,26 @@
-use chrono::Utc;
-use ratatui::prelude::Color;
-use ratatui::symbols::Marker;
 use ratatui::widgets::{Widget, Block};
-use ratatui::widgets::canvas::{Canvas, Context};
-use crate::friend::Friend;
+use ratatui::widgets::canvas::Context;
 use crate::friend::ShapeWrapper;
-use crate::shapes::{PixelImage, PixelVectorShape};
+use crate::game_state::GameState;
+use crate::renderer::Renderer;
+use crate::shapes::{PixelImage, PixelVectorShape, SkyShape, WeatherParticles};
+use crate::shapes::scenery::SceneryShape;
+use crate::day_cycle::DayPhase;
 use crate::utils::location::Location;
-use ratatui::layout::Rect;
+use ratatui::layout::{Position, Rect};
 use ratatui::text::Line;
 use crate::animations::{Animation, SleepingAnimation};
+use crate::theme::Theme;
 
 pub struct FriendWidget<'a> {
-    friend: &'a Friend,
+    game_state: &'a GameState,
     friend_location: Location,
     movement_area: Rect,
 }
 impl<'a> FriendWidget<'a> {
-    pub fn new(friend: &'a Friend, friend_location: Location, movement_area: Rect) -> Self {
+    pub fn new(game_state: &'a GameState, friend_location: Location, movement_area: Rect) -> Self {
         Self { 
-            friend, 
+            game_state, 
             friend_location,
             movement_area,
         }
@@ -28,46 +29,97 @@ impl<'a> FriendWidget<'a> {
     pub fn get_widget(&self) -> impl Widget + '_ {
         let friend_widget_x_bounds = [0.0, f64::from(self.movement_area.width)];
         let friend_widget_y_bounds = [0.0, f64::from(self.movement_area.height)];
+        let (width, height) = (f64::from(self.movement_area.width), f64::from(self.movement_area.height));
+        let friend = self.game_state.friend();
+        let lights_on = self.game_state.lights_on();
+        let now = self.game_state.now();
+        let weather = self.game_state.weather(now);
         
-        let canvas = Canvas::default()
-            .block(Block::bordered().title(Line::from(self.title_string()).centered()))
-            .marker(Marker::Braille)
-            .x_bounds(friend_widget_x_bounds)
-            .y_bounds(friend_widget_y_bounds)
-            .paint(|ctx| {
-                // TODO: Create simple background
-                // ctx.draw(&background or something);
-                // ctx.layer();
+        let canvas = Renderer::global()
+            .canvas(friend_widget_x_bounds, friend_widget_y_bounds, move |ctx| {
+                if lights_on {
+                    ctx.draw(&SkyShape::new(now, width, height));
+                    ctx.layer();
+                    ctx.draw(&SceneryShape::new(self.game_state.scenery(), width, height));
+                    ctx.layer();
+                    ctx.draw(&WeatherParticles::new(weather, now, width, height));
+                    ctx.layer();
+                }
+
+                // The items stay in the room when the lights go off, they are just harder to see.
+                for placed in self.game_state.items() {
+                    let shape = placed.shape(self.movement_area);
+                    match lights_on {
+                        true => ctx.draw(&shape),
+                        false => ctx.draw(&shape.recolor(|color| Theme::global().dimmed(color))),
+                    }
+                }
+                ctx.layer();
                 
-                match self.friend.get_shape_wrapper() {
+                match friend.get_shape_wrapper() {
                     ShapeWrapper::Growing(shape) => draw_shape_at_location(ctx, &shape, &self.friend_location),
                     ShapeWrapper::Adult(shape) => draw_shape_at_location(ctx, &shape, &self.friend_location),
                 };
                 
-                if self.friend.is_asleep() {
-                    let mut sleep_animation = SleepingAnimation::new(self.friend_location);
+                if friend.is_asleep() {
+                    let mut sleep_animation = SleepingAnimation::new(self.friend_location, now);
                     
                     if let Some(frame) = sleep_animation.next_frame() {
                         ctx.draw(&frame);
                     }
                 }
-            });
+            })
+            .block(Block::bordered().title(Line::from(self.title_string()).centered()));
         
-        match self.friend.is_asleep() {
-            true => canvas.background_color(Color::Black),
-            false => canvas.background_color(Color::Reset),
+        match lights_on {
+            true => canvas.background_color(Theme::global().sky_color(DayPhase::from_timestamp(now))),
+            false => canvas.background_color(Theme::global().lights_off_color()),
         }
     }
 
     fn title_string(&self) -> String {
-        let now = Utc::now().timestamp_millis();
-        let millis_alive = now - self.friend.time_created();
+        let now = self.game_state.now();
+        let friend = self.game_state.friend();
+        let millis_alive = now - friend.time_created();
         let hours_alive = millis_alive / 1000 / 60 / 60;
-        let name = self.friend.name();
-        format!("  {name}  |  Age: {hours_alive} hours  ")
+        let name = friend.name();
+        let weather = self.game_state.weather(now).name();
+        if friend.is_sleepy(now) {
+            format!("  {name}  |  Age: {hours_alive} hours  |  {weather}  |  Sleepy  ")
+        } else {
+            format!("  {name}  |  Age: {hours_alive} hours  |  {weather}  ")
+        }
     }
 }
 
+/// Returns the part of the playground that is drawn in a single cell of the terminal, which is used to
+/// find out what the player clicked on. The y-axis of the playground goes up, while the rows of the terminal go down.
+/// <br>
+/// ## parameters:
+/// * `widget_area` - The area of the terminal the friend widget is drawn in, including its border.
+/// * `movement_area` - The playground, as it is passed to `FriendWidget::new`.
+/// * `column` - The column of the cell in the terminal.
+/// * `row` - The row of the cell in the terminal.
+///
+/// ## returns:
+/// `None` when the cell is outside of the playground.
+pub fn playground_area_at(widget_area: Rect, movement_area: Rect, column: u16, row: u16) -> Option<Rect> {
+    let inner = Block::bordered().inner(widget_area);
+    if !inner.contains(Position::new(column, row)) {
+        return None;
+    }
+
+    let (column, row) = (u32::from(column - inner.x), u32::from(inner.bottom() - row));
+    let (inner_width, inner_height) = (u32::from(inner.width), u32::from(inner.height));
+    let (width, height) = (u32::from(movement_area.width), u32::from(movement_area.height));
+
+    let left = column * width / inner_width;
+    let right = (column + 1) * width / inner_width;
+    let bottom = (row - 1) * height / inner_height;
+    let top = row * height / inner_height;
+    Some(Rect::new(left as u16, bottom as u16, (right - left) as u16, (top - bottom) as u16))
+}
+
 fn draw_shape_at_location<S: PixelImage>(ctx: &mut Context, shape: &S, location: &Location) {
     let vec_shape = PixelVectorShape::from_pixel_image(shape)
         .translate(location.x as i32, location.y as i32);
@@ -75,3 +127,21 @@ fn draw_shape_at_location<S: PixelImage>(ctx: &mut Context, shape: &S, location:
     ctx.draw(&vec_shape);
 }
 
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+    use crate::widgets::friend_widget::playground_area_at;
+
+    #[test]
+    fn maps_cells_to_the_playground() {
+        // 10 by 5 cells inside the border, with 2 by 4 playground pixels per cell.
+        let widget_area = Rect::new(20, 10, 12, 7);
+        let movement_area = Rect::new(0, 0, 20, 20);
+
+        assert_eq!(playground_area_at(widget_area, movement_area, 21, 11), Some(Rect::new(0, 16, 2, 4)));
+        assert_eq!(playground_area_at(widget_area, movement_area, 30, 15), Some(Rect::new(18, 0, 2, 4)));
+        assert_eq!(playground_area_at(widget_area, movement_area, 20, 11), None);
+        assert_eq!(playground_area_at(widget_area, movement_area, 25, 16), None);
+    }
+}
@@ -0,0 +1,206 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::prelude::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Row, Table, Tabs, Widget, Wrap};
+use crate::day_cycle::{BEDTIME_HOUR, LATE_HOUR, WAKE_UP_HOUR};
+use crate::friend::{GrowthStage, ENERGY_DECAY_MILLIS, FOOD_DECAY_MILLIS, JOY_DECAY_MILLIS, MAX_EMPTY_STATS, MAX_SLEEP_MILLIS, MIN_STATS_SUM};
+use crate::keymap::{KeyAction, Keymap};
+
+const MINUTE_MILLIS: i64 = 1000 * 60;
+const HOUR_MILLIS: i64 = 60 * MINUTE_MILLIS;
+
+/// The pages of the help overlay, in the order they are shown.
+pub const HELP_PAGES: [HelpPage; 5] = [
+    HelpPage::Keys,
+    HelpPage::Stats,
+    HelpPage::Actions,
+    HelpPage::Growing,
+    HelpPage::Survival,
+];
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum HelpPage {
+    #[default]
+    Keys,
+    Stats,
+    Actions,
+    Growing,
+    Survival,
+}
+
+impl HelpPage {
+    pub fn title(&self) -> &'static str {
+        match self {
+            HelpPage::Keys => "Keys",
+            HelpPage::Stats => "Stats",
+            HelpPage::Actions => "Actions",
+            HelpPage::Growing => "Growing",
+            HelpPage::Survival => "Survival",
+        }
+    }
+
+    /// Returns the page after this one, going back to the first page after the last one.
+    pub fn next(&self) -> HelpPage {
+        let index = HELP_PAGES.iter().position(|page| page == self).unwrap_or(0);
+        HELP_PAGES[(index + 1) % HELP_PAGES.len()]
+    }
+}
+
+/// ## HelpWidget
+/// The help overlay, which explains the keys, the stats, the actions and the rules of the game.
+/// The player can flip through its pages with the switch key.
+pub struct HelpWidget<'a> {
+    page: HelpPage,
+    keymap: &'a Keymap,
+}
+
+impl<'a> HelpWidget<'a> {
+    pub fn new(page: HelpPage, keymap: &'a Keymap) -> Self {
+        Self { page, keymap }
+    }
+
+    /// Returns the height the help needs, so every page fits without changing the size of the overlay.
+    pub fn height(&self) -> u16 {
+        // One row per binding, plus the header, the tabs and the borders.
+        (self.keymap.bindings().count() as u16 + 5).max(18)
+    }
+
+    fn page_text(&self) -> Vec<Line<'static>> {
+        let grows_after = |stage: GrowthStage| duration(stage.grows_after_millis().unwrap_or_default());
+
+        let lines = match self.page {
+            HelpPage::Keys => return Vec::new(),
+            HelpPage::Stats => vec![
+                format!("Food goes down by 1 every {}. Eating fills it up again, better food fills it up more.", duration(FOOD_DECAY_MILLIS)),
+                String::new(),
+                format!(
+                    "Joy goes down by 1 every {}, and faster in the rain for pets that don't like water. Playing and petting cheer your pet up.",
+                    duration(JOY_DECAY_MILLIS),
+                ),
+                String::new(),
+                format!(
+                    "Energy goes down by 1 every {} while your pet is awake, and twice as fast on hot afternoons. \
+                    It comes back while your pet sleeps, most of all in a dark room and in a bed.",
+                    duration(ENERGY_DECAY_MILLIS),
+                ),
+                String::new(),
+                String::from("Health goes down for a while after every meal and every game. Only medicine brings it back up."),
+            ],
+            HelpPage::Actions => vec![
+                String::from("Eat - Feeds your pet the best food you have, buy more in the shop."),
+                String::from("Play - Plays a game, which gives joy and earns you some coins."),
+                String::from("Sleep - Puts your pet to bed, or wakes it up."),
+                String::from("Medicine - Heals your pet, using a dose you bought in the shop."),
+                String::from("Lights - Switches the lights on or off."),
+                String::from("Scenery - Changes the scenery of the playground."),
+                String::from("Shop - Spends your coins on food, medicine, toys and furniture."),
+                String::new(),
+                String::from("You can also click an action with your mouse, or click your pet to give it a pat."),
+                String::from("Eggs can't do anything yet, and a sleeping pet won't eat or play."),
+            ],
+            HelpPage::Growing => vec![
+                format!("Your egg hatches into a baby {} after you got it.", grows_after(GrowthStage::Egg)),
+                format!("The baby grows into a kid {} after you got it,", grows_after(GrowthStage::Baby)),
+                format!("and into an adult after {}. Only then you'll see what your pet really looks like.", grows_after(GrowthStage::Kid)),
+                String::new(),
+                format!(
+                    "From {BEDTIME_HOUR}:00 your pet gets sleepy and falls asleep on its own, it wakes up again at {WAKE_UP_HOUR:02}:00. \
+                    Keeping it awake after {LATE_HOUR}:00 makes it lose joy."
+                ),
+                format!("Your pet never sleeps longer than {} in a row.", duration(MAX_SLEEP_MILLIS)),
+            ],
+            HelpPage::Survival => vec![
+                String::from("Your pet dies when:"),
+                String::from("* its health drops to 0,"),
+                format!("* {MAX_EMPTY_STATS} or more of its stats are empty at the same time,"),
+                format!("* or its food, joy and health added together drop below {MIN_STATS_SUM}."),
+                String::new(),
+                String::from("Eggs only die when they get cold, so keep the lights on for your egg while it snows."),
+                String::from("Your coins, achievements and unlocked sceneries stay with you when you get a new pet."),
+            ],
+        };
+
+        lines.into_iter().map(Line::from).collect()
+    }
+}
+
+impl Widget for HelpWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::bordered()
+            .title(Line::from(" Help ").centered())
+            .title_bottom(Line::from(format!(
+                " '{}': next page, '{}': close ",
+                self.keymap.keys(KeyAction::Switch), self.keymap.keys(KeyAction::Help),
+            )).centered());
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [tabs_area, page_area] = Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).areas(inner);
+        let selected = HELP_PAGES.iter().position(|page| *page == self.page);
+        Tabs::new(HELP_PAGES.map(|page| page.title()))
+            .select(selected)
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .render(tabs_area, buf);
+
+        match self.page {
+            HelpPage::Keys => help_table(self.keymap).render(page_area, buf),
+            _ => Paragraph::new(self.page_text()).wrap(Wrap { trim: true }).render(page_area, buf),
+        }
+    }
+}
+
+/// Returns a table with every action and the keys that are bound to it.
+/// <br>
+/// ## parameters:
+/// * `keymap` - The keymap the player is using.
+pub fn help_table(keymap: &Keymap) -> Table<'static> {
+    let rows: Vec<Row> = keymap.bindings()
+        .map(|(action, keys)| Row::new(vec![keys, String::from(action.description())]))
+        .collect();
+
+    Table::new(rows, [Constraint::Length(12), Constraint::Fill(1)])
+        .header(Row::new(vec!["Keys", "Action"]).style(Style::new().add_modifier(Modifier::BOLD)))
+}
+
+/// Returns a rectangle of the given size centered in the area, shrunk to fit when the area is too small.
+/// <br>
+/// ## parameters:
+/// * `area` - The area to center the rectangle in.
+/// * `width` - The preferred width of the rectangle.
+/// * `height` - The preferred height of the rectangle.
+pub fn centered_area(area: Rect, width: u16, height: u16) -> Rect {
+    let [area] = Layout::horizontal([Constraint::Length(width)]).flex(Flex::Center).areas(area);
+    let [area] = Layout::vertical([Constraint::Length(height)]).flex(Flex::Center).areas(area);
+    area
+}
+
+/// Writes a duration in whole hours or minutes, like "5 hours".
+fn duration(millis: i64) -> String {
+    let (amount, unit) = if millis >= HOUR_MILLIS && millis % HOUR_MILLIS == 0 {
+        (millis / HOUR_MILLIS, "hour")
+    } else {
+        (millis / MINUTE_MILLIS, "minute")
+    };
+
+    if amount == 1 {
+        format!("{amount} {unit}")
+    } else {
+        format!("{amount} {unit}s")
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::widgets::help_widget::{duration, HelpPage, HELP_PAGES};
+
+    #[test]
+    fn pages_wrap_around() {
+        assert_eq!(HelpPage::Keys.next(), HelpPage::Stats);
+        assert_eq!(HELP_PAGES[HELP_PAGES.len() - 1].next(), HelpPage::Keys);
+
+        assert_eq!(duration(16 * 60 * 1000), "16 minutes");
+        assert_eq!(duration(24 * 60 * 60 * 1000), "24 hours");
+    }
+}
@@ -0,0 +1,13 @@
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Wrap};
+
+/// Returns a box with a message for the player, like the reason an action couldn't be performed.
+/// <br>
+/// ## parameters:
+/// * `message` - The message to show.
+pub fn message_box(message: &str) -> Paragraph<'static> {
+    Paragraph::new(String::from(message))
+        .block(Block::bordered().title(Line::from(" Message ").centered()))
+        .centered()
+        .wrap(Wrap { trim: true })
+}
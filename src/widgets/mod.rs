@@ -3,7 +3,15 @@ mod stats_widget;
 pub mod actions_widget;
 pub mod new_game_widget;
 pub mod friend_death_widget;
+pub mod shop_widget;
+pub mod achievements_widget;
+pub mod statistics_widget;
+pub mod help_widget;
+mod tutorial_widget;
+pub mod too_small_widget;
+pub mod message_widget;
 
-pub use friend_widget::FriendWidget;
-pub use actions_widget::actions_widget;
-pub use stats_widget::StatsWidgetGenerator;
\ No newline at end of file
+pub use friend_widget::{FriendWidget, playground_area_at};
+pub use actions_widget::{actions_widget, actions_hint};
+pub use stats_widget::StatsWidgetGenerator;
+pub use tutorial_widget::TutorialWidget;
\ No newline at end of file
@@ -5,19 +5,25 @@ use ratatui::{
     text::Line,
     widgets::{Block, Paragraph, Wrap},
 };
+use crate::keymap::{KeyAction, Keymap};
 
 pub fn new_game_dialog<'a>() -> impl Widget + 'a {
+    let keymap = Keymap::global();
     let text = vec![
         Line::from("Welcome to Termagotchi! Since this is your first time, I'll explain the basics."),
         Line::from(""),
         Line::from("Your goal is to keep your pet alive by taking good care of it."),
         Line::from("To keep your pet healthy, its stats need to stay as high as possible. They are visible on the left."),
         Line::from("You can raise a stat by performing the appropriate action."),
-        Line::from("To navigate the 'actions' menu, use the 'Up' and 'Down' keys on your keyboard. Press 'Enter' to perform the action."),
+        Line::from(format!(
+            "To navigate the 'actions' menu, use the '{}' and '{}' keys on your keyboard. Press '{}' to perform the action.",
+            keymap.keys(KeyAction::Up), keymap.keys(KeyAction::Down), keymap.keys(KeyAction::Select),
+        )),
+        Line::from(format!("Press '{}' at any time to see all keys you can use.", keymap.keys(KeyAction::Help))),
         Line::from(""),
-        Line::from("If you want to close the game you can press 'q', or you can always just close the terminal."),
+        Line::from(format!("If you want to close the game you can press '{}', or you can always just close the terminal.", keymap.keys(KeyAction::Quit))),
         Line::from(""),
-        Line::from("To start, what should your first pet be called? Enter a name and then press 'Enter' to continue."),
+        Line::from(format!("To start, what should your first pet be called? Enter a name and then press '{}' to continue.", keymap.keys(KeyAction::Select))),
     ];
     Paragraph::new(text)
         .block(Block::bordered())
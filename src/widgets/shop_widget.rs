@@ -0,0 +1,51 @@
+use ratatui::prelude::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListItem, Paragraph, Widget};
+use crate::game_state::GameState;
+use crate::keymap::{KeyAction, Keymap};
+use crate::shop::SHOP_ITEMS;
+
+/// Returns the list of everything that can be bought in the shop, with their prices.
+/// Items the player already owns are marked as such.
+/// <br>
+/// ## parameters:
+/// * `game_state` - The game state, used to check what the player already owns.
+pub fn shop_list(game_state: &GameState) -> List<'static> {
+    let items: Vec<ListItem> = SHOP_ITEMS.iter()
+        .map(|shop_item| {
+            let status = if game_state.can_buy(*shop_item) {
+                format!("{} coins", shop_item.price())
+            } else {
+                String::from("owned")
+            };
+            ListItem::new(format!("{:<20}{status:>10}", shop_item.name()))
+        })
+        .collect();
+
+    List::new(items)
+        .block(Block::bordered().title(Line::from(" Shop ").centered()))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+}
+
+/// Shows the coins and supplies the player currently has.
+/// <br>
+/// ## parameters:
+/// * `game_state` - The game state to get the inventory from.
+/// * `message` - Why the last purchase failed, if it did.
+pub fn wallet_summary(game_state: &GameState, message: Option<&str>) -> impl Widget + 'static {
+    let inventory = game_state.inventory();
+    let keymap = Keymap::global();
+    let text = vec![
+        Line::from(format!("Coins: {}", inventory.coins())),
+        Line::from(format!("Food: {}  |  Medicine: {}", inventory.food_count(), inventory.medicine())),
+        Line::from(message.map(String::from).unwrap_or_default()),
+        Line::from(format!(
+            "Press '{}' to buy the selected item, or '{}' to go back.",
+            keymap.keys(KeyAction::Select), keymap.keys(KeyAction::Back),
+        )),
+    ];
+
+    Paragraph::new(text)
+        .block(Block::bordered().title(Line::from(" Wallet ").centered()))
+        .centered()
+}
@@ -0,0 +1,136 @@
+use ratatui::layout::Constraint;
+use ratatui::style::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Axis, Block, Chart, Dataset, GraphType, Paragraph, Widget};
+use crate::history::StatHistory;
+use crate::keymap::{KeyAction, Keymap};
+use crate::renderer::Renderer;
+use crate::theme::Theme;
+
+const HOUR_MILLIS: i64 = 1000 * 60 * 60;
+
+/// The period of time shown in the statistics chart.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HistoryRange {
+    Day,
+    Week,
+}
+
+impl HistoryRange {
+    /// The amount of hours covered by this range.
+    pub fn hours(&self) -> i64 {
+        match self {
+            HistoryRange::Day => 24,
+            HistoryRange::Week => 24 * 7,
+        }
+    }
+
+    /// Switches between showing the last day and the last week.
+    pub fn toggle(&mut self) {
+        *self = match self {
+            HistoryRange::Day => HistoryRange::Week,
+            HistoryRange::Week => HistoryRange::Day,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            HistoryRange::Day => "last day",
+            HistoryRange::Week => "last week",
+        }
+    }
+}
+
+/// Generates the widgets of the statistics screen, which shows how the friend's stats changed over time.
+/// ## Fields:
+/// * `range` - The period of time shown in the chart.
+/// * `points` - The points of the food, joy, energy and health lines, as hours relative to now and stat values.
+/// * `history` - The history the points were taken from, used for the lifetime totals.
+pub struct StatisticsWidgetGenerator<'a> {
+    range: HistoryRange,
+    points: [Vec<(f64, f64)>; 4],
+    history: &'a StatHistory,
+}
+
+impl<'a> StatisticsWidgetGenerator<'a> {
+    /// Creates a new `StatisticsWidgetGenerator`.
+    /// <br>
+    /// ## parameters:
+    /// * `history` - The recorded stats of the friend.
+    /// * `range` - The period of time that should be shown.
+    /// * `now` - The current utc time in millis.
+    pub fn new(history: &'a StatHistory, range: HistoryRange, now: i64) -> Self {
+        let mut points: [Vec<(f64, f64)>; 4] = Default::default();
+        for sample in history.samples_since(now - range.hours() * HOUR_MILLIS) {
+            let hours_ago = (sample.time - now) as f64 / HOUR_MILLIS as f64;
+            let values = [sample.food, sample.joy, sample.energy, sample.health];
+            for (line, value) in points.iter_mut().zip(values) {
+                line.push((hours_ago, f64::from(value)));
+            }
+        }
+
+        Self {
+            range,
+            points,
+            history,
+        }
+    }
+
+    /// Returns a chart with a line for each of the friend's stats.
+    pub fn get_chart(&self) -> Chart<'_> {
+        let names = ["Food", "Joy", "Energy", "Health"];
+        let colors = [Color::Yellow, Color::Magenta, Color::Cyan, Color::Green].map(|color| Theme::global().paint(color));
+
+        let datasets = names.iter()
+            .zip(colors)
+            .zip(&self.points)
+            .map(|((name, color), points)| {
+                Dataset::default()
+                    .name(*name)
+                    .marker(Renderer::global().marker())
+                    .graph_type(GraphType::Line)
+                    .style(Style::new().fg(color))
+                    .data(points)
+            })
+            .collect();
+
+        let hours = self.range.hours();
+        let x_labels = match self.range {
+            HistoryRange::Day => vec![format!("-{hours}h"), format!("-{}h", hours / 2), String::from("now")],
+            HistoryRange::Week => vec![format!("-{}d", hours / 24), format!("-{}d", hours / 48), String::from("now")],
+        };
+
+        let title = format!(" Stats over the {} ", self.range.name());
+        Chart::new(datasets)
+            .block(Block::bordered().title(Line::from(title).centered()))
+            .x_axis(Axis::default()
+                .bounds([-(hours as f64), 0.0])
+                .labels(x_labels))
+            .y_axis(Axis::default()
+                .bounds([0.0, 100.0])
+                .labels(["0", "50", "100"]))
+            // Keep the legend visible on smaller terminals, it is needed to tell the lines apart.
+            .hidden_legend_constraints((Constraint::Ratio(1, 3), Constraint::Ratio(1, 2)))
+    }
+
+    /// Returns the totals of all care the friend received during its life.
+    pub fn get_totals(&self) -> impl Widget + 'static {
+        let totals = self.history.totals();
+        let keymap = Keymap::global();
+        let text = vec![
+            Line::from(format!(
+                "Meals: {}  |  Play sessions: {}  |  Medicine doses: {}",
+                totals.meals, totals.play_sessions, totals.medicine_doses,
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Press '{}' to switch between the last day and week, or '{}' to go back.",
+                keymap.keys(KeyAction::Switch), keymap.keys(KeyAction::Back),
+            )),
+        ];
+
+        Paragraph::new(text)
+            .block(Block::bordered().title(Line::from(" Lifetime totals ").centered()))
+            .centered()
+    }
+}
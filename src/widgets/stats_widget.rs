@@ -1,39 +1,41 @@
 use ratatui::{
     layout::Direction,
-    style::{Color, Style, Stylize},
+    style::{Style, Stylize},
     text::Line,
-    widgets::{Bar, BarChart, BarGroup, Block},
+    widgets::{Bar, BarChart, BarGroup, Block, LineGauge},
 };
 use crate::friend::Friend;
 use crate::utils::Stat;
+use crate::theme::Theme;
 
 /// Manages the state of the *"Stats widget"* and allows for generating the widget so it can be 
 /// rendered in the TUI.
 /// ## Fields:
 /// * `friend` - A reference to the current Termagotchi pet, used to get the stats of the pet.
+/// * `stats` - The name and value of every stat of the creature.
 /// * `bars` - The `ratatui::widgets::Bar` items that should be displayed in the widget, each corresponding
 ///   a specific creature stat.
 pub struct StatsWidgetGenerator<'a> {
+    stats: [(&'static str, Stat); 4],
     bars: [Bar<'a>; 4],
 }
 impl<'a> StatsWidgetGenerator<'a> {
     pub fn new(friend: &'a Friend) -> Self {
+        let stats = [
+            ("Food", *friend.food()),
+            ("Joy", *friend.joy()),
+            ("Energy", *friend.energy()),
+            ("Health", *friend.health()),
+        ];
+
         Self {
-            bars: Self::generate_stat_bars(friend),
+            stats,
+            bars: stats.map(|(name, stat)| Self::generate_stat_bar(name, stat)),
         }
     }
-
-    fn generate_stat_bars(friend: &Friend) -> [Bar<'a>; 4] {
-        [
-            Self::generate_stat_bar("Food", *friend.food()),
-            Self::generate_stat_bar("Joy", *friend.joy()),
-            Self::generate_stat_bar("Energy", *friend.energy()),
-            Self::generate_stat_bar("Health", *friend.health()),
-        ]
-    }
     
     /// Returns the widget that can be rendered in the TUI with all the stats of the creature displayed.
-    pub fn get_widget(&self) -> BarChart {
+    pub fn get_widget(&self) -> BarChart<'_> {
         let title = Line::from(" Stats ").centered();
         BarChart::default()
             .block(Block::bordered().title(title))
@@ -43,7 +45,17 @@ impl<'a> StatsWidgetGenerator<'a> {
             .direction(Direction::Horizontal)
     }
 
-    fn generate_stat_bar(stat_name: &str, stat: Stat) -> Bar {
+    /// Returns a single line gauge for every stat, used when there is little room on the screen.
+    pub fn get_gauges(&self) -> [LineGauge<'static>; 4] {
+        self.stats.map(|(name, stat)| {
+            LineGauge::default()
+                .label(format!("{name:<6}{:>4} ", stat.value()))
+                .ratio(f64::from(stat.value()) / 100.0)
+                .filled_style(Self::stat_style(stat))
+        })
+    }
+
+    fn generate_stat_bar(stat_name: &str, stat: Stat) -> Bar<'_> {
         // Using the stat.value() as a u8 here is safe, since the stats value can at max be 100.
         let style = Self::stat_style(stat);
         Bar::default()
@@ -54,12 +66,9 @@ impl<'a> StatsWidgetGenerator<'a> {
             .value_style(style.reversed())
     }
 
-    /// create a yellow to red value based on the value (50-90)
+    /// create a red to green color based on the value, using the colors of the current theme
     fn stat_style(stat: Stat) -> Style {
-        let green = (stat.value() * 2) as u8;
-        let red = 200 - green;
-        let color = Color::Rgb(red, green, 0);
-        Style::new().fg(color)
+        Style::new().fg(Theme::global().stat_color(stat.value()))
     }
 }
 
@@ -0,0 +1,21 @@
+use ratatui::layout::Rect;
+use ratatui::text::Line;
+use ratatui::widgets::{Paragraph, Wrap};
+
+/// Returns a message asking the player to make their terminal larger, short enough to fit in the tiniest of terminals.
+/// <br>
+/// ## parameters:
+/// * `area` - The current size of the terminal.
+/// * `min_width` - The smallest width the game can be played at.
+/// * `min_height` - The smallest height the game can be played at.
+pub fn too_small_message(area: Rect, min_width: u16, min_height: u16) -> Paragraph<'static> {
+    let text = vec![
+        Line::from("Terminal too small"),
+        Line::from(format!("Needs {min_width}x{min_height}")),
+        Line::from(format!("Now {}x{}", area.width, area.height)),
+    ];
+
+    Paragraph::new(text)
+        .centered()
+        .wrap(Wrap { trim: true })
+}
@@ -0,0 +1,50 @@
+use ratatui::layout::Alignment;
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Clear, Paragraph, Widget, Wrap};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use crate::game_state::GameState;
+use crate::keymap::{KeyAction, Keymap};
+
+/// ## TutorialWidget
+/// Shows the current step of the tutorial for new players, on top of the playground.
+pub struct TutorialWidget<'a> {
+    game_state: &'a GameState,
+    keymap: &'a Keymap,
+}
+
+impl<'a> TutorialWidget<'a> {
+    pub fn new(game_state: &'a GameState, keymap: &'a Keymap) -> Self {
+        Self { game_state, keymap }
+    }
+
+    /// Returns the area at the bottom of the playground the tutorial is drawn in.
+    /// <br>
+    /// ## parameters:
+    /// * `friend_area` - The area the friend widget is drawn in.
+    pub fn area(friend_area: Rect) -> Rect {
+        let height = 5.min(friend_area.height.saturating_sub(2));
+        Rect::new(
+            friend_area.x + 1,
+            (friend_area.y + friend_area.height).saturating_sub(height + 1),
+            friend_area.width.saturating_sub(2),
+            height,
+        )
+    }
+}
+
+impl Widget for TutorialWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let tutorial = self.game_state.tutorial();
+        let (step, steps) = tutorial.step();
+
+        Clear.render(area, buf);
+        Paragraph::new(tutorial.instructions(self.game_state.friend(), self.keymap))
+            .block(Block::bordered()
+                .title(Line::from(format!(" Tutorial ({step}/{steps}) ")).centered())
+                .title_bottom(Line::from(format!(" '{}': skip ", self.keymap.keys(KeyAction::Back))).right_aligned()))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .render(area, buf);
+    }
+}